@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+use std::fmt;
+
+const WORD_BITS: usize = 64;
+
+/// Rule of a one dimensional cellular automaton: whether a cell is alive in the next
+/// generation for every neighbourhood of `2 * radius + 1` cells around it.
+///
+/// Neighbourhoods are looked up by index, the leftmost cell is the highest bit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule {
+    radius: usize,
+    table: Vec<bool>,
+}
+
+impl Rule {
+    /// Rule killing every cell, `set` the neighbourhoods which come alive.
+    pub fn new(radius: usize) -> Self {
+        assert!(radius <= 8, "neighbourhood of radius {radius} is too big");
+        Self {
+            radius,
+            table: vec![false; 1 << (2 * radius + 1)],
+        }
+    }
+
+    pub fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Number of cells in a neighbourhood.
+    pub fn width(&self) -> usize {
+        2 * self.radius + 1
+    }
+
+    pub fn set(&mut self, neighbourhood: &[bool], alive: bool) {
+        assert_eq!(
+            neighbourhood.len(),
+            self.width(),
+            "wrong neighbourhood size"
+        );
+        let idx = neighbourhood
+            .iter()
+            .fold(0, |idx, cell| idx << 1 | usize::from(*cell));
+        self.table[idx] = alive;
+    }
+
+    pub fn get(&self, idx: usize) -> bool {
+        self.table[idx]
+    }
+}
+
+/// Live cells of an infinite row of otherwise dead cells, packed into bits.
+///
+/// The first live cell is always bit 0 of the first word and there are no empty words at
+/// the end, so rows with the same shape compare equal by `words` wherever they are.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Row {
+    offset: isize,
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl Row {
+    /// Row with `cells` starting at position `offset`.
+    pub fn new(offset: isize, cells: impl IntoIterator<Item = bool>) -> Self {
+        let mut row = Row::default();
+        let mut cells = cells.into_iter();
+        match cells.position(|cell| cell) {
+            Some(first) => row.offset = offset + first as isize,
+            None => return row,
+        }
+        row.push(true);
+        for cell in cells {
+            row.push(cell);
+        }
+        row.trim();
+        row
+    }
+
+    fn push(&mut self, cell: bool) {
+        if self.len.is_multiple_of(WORD_BITS) {
+            self.words.push(0);
+        }
+        if cell {
+            *self.words.last_mut().unwrap() |= 1 << (self.len % WORD_BITS);
+        }
+        self.len += 1;
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+        self.len = match self.words.last() {
+            Some(word) => {
+                (self.words.len() - 1) * WORD_BITS + (WORD_BITS - word.leading_zeros() as usize)
+            }
+            None => 0,
+        };
+    }
+
+    fn bit(&self, idx: usize) -> bool {
+        idx < self.len && self.words[idx / WORD_BITS] & (1 << (idx % WORD_BITS)) != 0
+    }
+
+    pub fn get(&self, pos: isize) -> bool {
+        pos >= self.offset && self.bit((pos - self.offset) as usize)
+    }
+
+    /// Position of the first live cell, 0 for an empty row.
+    pub fn offset(&self) -> isize {
+        self.offset
+    }
+
+    /// Distance from the first live cell to just after the last one.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Positions of all the live cells.
+    pub fn cells(&self) -> impl Iterator<Item = isize> + '_ {
+        (0..self.len)
+            .filter(|idx| self.bit(*idx))
+            .map(|idx| self.offset + idx as isize)
+    }
+
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Sum of the positions of the live cells.
+    pub fn sum(&self) -> isize {
+        self.cells().sum()
+    }
+
+    pub fn shifted(&self, by: isize) -> Row {
+        Row {
+            offset: self.offset + by,
+            ..self.clone()
+        }
+    }
+
+    /// Whether both rows have the same live cells relative to their first one.
+    pub fn same_shape(&self, other: &Row) -> bool {
+        self.words == other.words
+    }
+
+    /// Next generation, cells can only come alive up to `radius` cells outside the row.
+    ///
+    /// The neighbourhood index is kept up to date by shifting in one cell at a time.
+    pub fn step(&self, rule: &Rule) -> Row {
+        assert!(!rule.get(0), "dead cells can't come alive on their own");
+        let radius = rule.radius;
+        // the window ends at the cell `radius` past the one it decides
+        let mask = (1 << rule.width()) - 1;
+        let mut idx = 0;
+        let cells = (0..self.len + 2 * radius).map(|pos| {
+            idx = (idx << 1 | usize::from(self.bit(pos))) & mask;
+            rule.get(idx)
+        });
+        Row::new(self.offset - radius as isize, cells)
+    }
+}
+
+impl fmt::Display for Row {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for idx in 0..self.len {
+            write!(f, "{}", if self.bit(idx) { '#' } else { '.' })?;
+        }
+        Ok(())
+    }
+}
+
+/// The row at generation `start` comes back `period` generations later moved `shift`
+/// cells to the right, a shift other than 0 makes it a glider.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: u64,
+    pub period: u64,
+    pub shift: isize,
+}
+
+/// Steps `row` until its shape repeats, gives up after `limit` generations.
+pub fn find_cycle(rule: &Rule, row: &Row, limit: u64) -> Option<Cycle> {
+    let mut seen: HashMap<Vec<u64>, (u64, isize)> = HashMap::new();
+    let mut row = row.clone();
+    for generation in 0..=limit {
+        if let Some((start, offset)) = seen.insert(row.words.clone(), (generation, row.offset)) {
+            return Some(Cycle {
+                start,
+                period: generation - start,
+                shift: row.offset - offset,
+            });
+        }
+        row = row.step(rule);
+    }
+    None
+}
+
+/// The row `generations` later, jumping over whole cycles once the shape repeats.
+///
+/// Rows which never repeat (growing for ever) are simply stepped through.
+pub fn after(rule: &Rule, row: &Row, generations: u64) -> Row {
+    let mut seen: HashMap<Vec<u64>, (u64, isize)> = HashMap::new();
+    let mut row = row.clone();
+    let mut generation = 0;
+    while generation < generations {
+        if let Some((start, offset)) = seen.insert(row.words.clone(), (generation, row.offset)) {
+            let (period, shift) = (generation - start, row.offset - offset);
+            let cycles = (generations - generation) / period;
+            row = row.shifted(cycles as isize * shift);
+            for _ in 0..(generations - generation) % period {
+                row = row.step(rule);
+            }
+            return row;
+        }
+        row = row.step(rule);
+        generation += 1;
+    }
+    row
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    fn row(offset: isize, cells: &str) -> Row {
+        Row::new(offset, cells.chars().map(|c| c == '#'))
+    }
+
+    /// Rule from its Wolfram code, for radius 1.
+    fn elementary(code: u8) -> Rule {
+        let mut rule = Rule::new(1);
+        for idx in 0..8 {
+            rule.table[idx] = code & (1 << idx) != 0;
+        }
+        rule
+    }
+
+    fn brute_force_after(rule: &Rule, row: &Row, generations: u64) -> Row {
+        let radius = rule.radius() as isize;
+        let mut cells: BTreeSet<isize> = row.cells().collect();
+        for _ in 0..generations {
+            let (min, max) = match (cells.first(), cells.last()) {
+                (Some(min), Some(max)) => (*min, *max),
+                _ => break,
+            };
+            cells = ((min - radius)..=(max + radius))
+                .filter(|pos| {
+                    let idx = ((pos - radius)..=(pos + radius))
+                        .fold(0, |idx, p| idx << 1 | usize::from(cells.contains(&p)));
+                    rule.get(idx)
+                })
+                .collect();
+        }
+        let offset = cells.first().copied().unwrap_or(0);
+        let len = cells.last().map_or(0, |last| last - offset + 1);
+        Row::new(
+            offset,
+            (offset..offset + len).map(|pos| cells.contains(&pos)),
+        )
+    }
+
+    #[test]
+    fn test_row() {
+        let r = row(-3, "..#.#..##...");
+        assert_eq!((r.offset(), r.len(), r.count()), (-1, 7, 4));
+        assert_eq!(r.to_string(), "#.#..##");
+        assert_eq!(r.cells().collect::<Vec<_>>(), vec![-1, 1, 4, 5]);
+        assert_eq!(r.sum(), 9);
+        assert!(r.get(4) && !r.get(3) && !r.get(-2));
+        assert!(r.same_shape(&r.shifted(100)));
+        assert!(row(5, "....").is_empty());
+
+        let long = row(0, &"#.".repeat(100));
+        assert_eq!((long.len(), long.count()), (199, 100));
+        assert_eq!(long.step(&elementary(90)).len(), 201);
+    }
+
+    #[test]
+    fn test_step() {
+        // rule 90 draws the Sierpinski triangle
+        let mut r = row(0, "#");
+        let rule = elementary(90);
+        let mut lines = Vec::new();
+        for _ in 0..4 {
+            lines.push(format!("{} {}", r.offset(), r));
+            r = r.step(&rule);
+        }
+        assert_eq!(lines, vec!["0 #", "-1 #.#", "-2 #...#", "-3 #.#.#.#"]);
+    }
+
+    #[test]
+    fn test_cycles() {
+        // every cell moves one to the right
+        let glider = elementary(0b1111_0000);
+        let r = row(0, "##.#");
+        let cycle = find_cycle(&glider, &r, 10);
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                start: 0,
+                period: 1,
+                shift: 1
+            })
+        );
+        assert_eq!(after(&glider, &r, 50_000_000_000).offset(), 50_000_000_000);
+
+        // rule 90 keeps growing from a single cell
+        assert_eq!(find_cycle(&elementary(90), &row(0, "#"), 100), None);
+    }
+
+    #[test]
+    fn test_random_rules() {
+        let mut rng = StdRng::seed_from_u64(12);
+        for _ in 0..100 {
+            let radius = rng.gen_range(1..=2);
+            let mut rule = Rule::new(radius);
+            for idx in 1..rule.table.len() {
+                rule.table[idx] = rng.gen_bool(0.3);
+            }
+            let r = Row::new(rng.gen_range(-10..10), (0..20).map(|_| rng.gen_bool(0.5)));
+            for generations in [0, 1, 7, 150] {
+                assert_eq!(
+                    after(&rule, &r, generations),
+                    brute_force_after(&rule, &r, generations),
+                    "{rule:?} {r}"
+                );
+            }
+        }
+    }
+}
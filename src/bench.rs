@@ -0,0 +1,368 @@
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use aoc_helpers::scaffold::{Parse, Problem};
+use serde::{Deserialize, Serialize};
+
+use crate::parse::{CheckInput, ParseError};
+
+/// How many times each stage runs, warmup runs aren't measured.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Settings {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            samples: 5,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", with = "nanos")]
+    pub max: Duration,
+}
+
+/// Durations are stored as a plain number of nanoseconds.
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timing of a single stage (`parse`, `part1` or `part2`) of a day.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub stage: String,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+pub fn measure<R>(settings: &Settings, mut f: impl FnMut() -> R) -> Stats {
+    for _ in 0..settings.warmup {
+        black_box(f());
+    }
+    let samples = (0..settings.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+/// Measures parsing and both parts separately, parts get an already parsed input.
+pub fn problem<P>(
+    day: u8,
+    input: &str,
+    settings: &Settings,
+    with_part2: bool,
+) -> Result<Vec<Record>, ParseError>
+where
+    P: Problem,
+    P::Input: CheckInput<Parsed = <P::Input as Parse>::Parsed>,
+{
+    let parsed = <P::Input as CheckInput>::parse_checked(input)?;
+    let record = |stage: &str, stats| Record {
+        day,
+        stage: stage.to_string(),
+        stats,
+    };
+    let mut records = vec![
+        record(
+            "parse",
+            measure(settings, || <P::Input as CheckInput>::parse_checked(input)),
+        ),
+        record("part1", measure(settings, || P::solve_part1(&parsed))),
+    ];
+    if with_part2 {
+        records.push(record(
+            "part2",
+            measure(settings, || P::solve_part2(&parsed)),
+        ));
+    }
+    Ok(records)
+}
+
+/// A single benchmark run as stored in the history file.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Run {
+    pub timestamp: u64,
+    #[serde(flatten)]
+    pub settings: Settings,
+    pub records: Vec<Record>,
+}
+
+impl Run {
+    pub fn new(settings: Settings, records: Vec<Record>) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            settings,
+            records,
+        }
+    }
+}
+
+/// All the runs so far, oldest first.
+///
+/// Stored as JSON: `{"runs": [{"timestamp", "warmup", "samples", "records": [{"day", "stage",
+/// "min_ns", "median_ns", "max_ns"}]}]}`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    /// Loads the history, a missing file is an empty history.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::from_json(&text)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        if let Some(dir) = path.as_ref().parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_json())
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("history is always serializable") + "\n"
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// The most recent record of the stage, days aren't always benchmarked all together.
+    pub fn previous(&self, day: u8, stage: &str) -> Option<&Record> {
+        self.runs
+            .iter()
+            .rev()
+            .flat_map(|run| run.records.iter())
+            .find(|record| record.day == day && record.stage == stage)
+    }
+}
+
+/// A record next to the median of its previous run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Comparison {
+    pub record: Record,
+    pub previous: Option<Duration>,
+    pub regression: bool,
+}
+
+/// Flags records whose median got slower by more than `threshold` (relative).
+///
+/// Very quick stages are too noisy to compare so anything below `MIN_REGRESSION` is ignored.
+pub fn compare(records: &[Record], history: &History, threshold: f64) -> Vec<Comparison> {
+    records
+        .iter()
+        .map(|record| {
+            let previous = history
+                .previous(record.day, &record.stage)
+                .map(|previous| previous.stats.median);
+            let regression = previous.is_some_and(|previous| {
+                let current = record.stats.median;
+                current > previous + MIN_REGRESSION
+                    && current.as_secs_f64() > previous.as_secs_f64() * (1.0 + threshold)
+            });
+            Comparison {
+                record: record.clone(),
+                previous,
+                regression,
+            }
+        })
+        .collect()
+}
+
+pub const MIN_REGRESSION: Duration = Duration::from_micros(100);
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(day: u8, stage: &str, median_us: u64) -> Record {
+        let median = Duration::from_micros(median_us);
+        Record {
+            day,
+            stage: stage.to_string(),
+            stats: Stats {
+                min: median / 2,
+                median,
+                max: median * 2,
+            },
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(vec![
+            Duration::from_millis(5),
+            Duration::from_millis(1),
+            Duration::from_millis(3),
+        ]);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        measure(
+            &Settings {
+                warmup: 2,
+                samples: 3,
+            },
+            || calls += 1,
+        );
+        assert_eq!(calls, 5);
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let history = History {
+            runs: vec![
+                Run {
+                    timestamp: 1_543_622_400,
+                    settings: Settings::default(),
+                    records: vec![
+                        record(1, "parse", 10),
+                        record(1, "part1", 2_000),
+                        record(3, "part 2 \"naïve\"\\\t\n\u{7}", 5),
+                    ],
+                },
+                Run {
+                    timestamp: 1_543_708_800,
+                    settings: Settings {
+                        warmup: 0,
+                        samples: 1,
+                    },
+                    records: vec![],
+                },
+            ],
+        };
+        let json = history.to_json();
+        assert!(json.contains(r#""stage": "part 2 \"naïve\"\\\t\n\u0007""#));
+        assert!(json.contains(r#""median_ns": 2000000"#));
+        assert_eq!(History::from_json(&json).unwrap(), history);
+
+        assert_eq!(
+            History::from_json(r#"{"runs": []}"#).unwrap(),
+            History::default()
+        );
+        assert!(History::from_json(r#"{"runs": [{"timestamp": 1}]}"#).is_err());
+    }
+
+    #[test]
+    fn test_history_file() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-bench-{}", std::process::id()))
+            .join("history.json");
+        assert_eq!(History::load(&path).unwrap(), History::default());
+        let history = History {
+            runs: vec![Run::new(Settings::default(), vec![record(1, "part1", 7)])],
+        };
+        history.save(&path).unwrap();
+        assert_eq!(History::load(&path).unwrap(), history);
+        fs::write(&path, "run 1 1 5\n").unwrap();
+        assert_eq!(
+            History::load(&path).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = History {
+            runs: vec![
+                Run::new(
+                    Settings::default(),
+                    vec![record(1, "part1", 1_000), record(2, "part1", 1_000)],
+                ),
+                Run::new(Settings::default(), vec![record(1, "part1", 2_000)]),
+            ],
+        };
+        let comparisons = compare(
+            &[
+                record(1, "part1", 2_100),
+                record(2, "part1", 1_500),
+                record(3, "part1", 10),
+            ],
+            &history,
+            0.1,
+        );
+        assert_eq!(
+            comparisons
+                .iter()
+                .map(|c| (c.previous, c.regression))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some(Duration::from_micros(2_000)), false),
+                (Some(Duration::from_micros(1_000)), true),
+                (None, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_secs(3)), "3.00s");
+    }
+}
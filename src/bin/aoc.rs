@@ -0,0 +1,288 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+
+use advent_of_code_2018::bench::{self, History, Run, Settings};
+use advent_of_code_2018::days::{self, Day};
+
+const USAGE: &str = "usage:
+    aoc list
+    aoc run <day> [--part 1|2] [--input <path>|-]
+    aoc run --all
+    aoc bench <day>|--all [--samples <n>] [--warmup <n>] [--history <path>] [--no-save]";
+
+/// Relative slowdown of the median that counts as a regression.
+const REGRESSION_THRESHOLD: f64 = 0.1;
+
+enum Input {
+    Default,
+    Stdin,
+    File(String),
+}
+
+enum Command {
+    List,
+    Run {
+        day: u8,
+        part: Option<u8>,
+        input: Input,
+    },
+    RunAll,
+    Bench {
+        day: Option<u8>,
+        settings: Settings,
+        history: PathBuf,
+        save: bool,
+    },
+}
+
+fn parse_count(value: Option<String>, name: &str) -> Result<usize, String> {
+    value
+        .as_deref()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("invalid {name}: {value:?}"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("list") => Ok(Command::List),
+        Some("run") => {
+            let mut day = None;
+            let mut part = None;
+            let mut input = Input::Default;
+            let mut all = false;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--all" => all = true,
+                    "--part" => {
+                        part = match args.next().as_deref() {
+                            Some("1") => Some(1),
+                            Some("2") => Some(2),
+                            other => return Err(format!("invalid part: {other:?}")),
+                        }
+                    }
+                    "--input" => {
+                        input = match args.next() {
+                            Some(path) if path == "-" => Input::Stdin,
+                            Some(path) => Input::File(path),
+                            None => return Err("missing input path".to_string()),
+                        }
+                    }
+                    other => {
+                        day = Some(
+                            other
+                                .parse()
+                                .map_err(|_| format!("invalid day: {other:?}"))?,
+                        )
+                    }
+                }
+            }
+            match (all, day) {
+                (true, None) => Ok(Command::RunAll),
+                (true, Some(_)) => Err("--all doesn't take a day".to_string()),
+                (false, Some(day)) => Ok(Command::Run { day, part, input }),
+                (false, None) => Err("missing day".to_string()),
+            }
+        }
+        Some("bench") => {
+            let mut day = None;
+            let mut all = false;
+            let mut settings = Settings::default();
+            let mut history = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("bench")
+                .join("history.json");
+            let mut save = true;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--all" => all = true,
+                    "--samples" => settings.samples = parse_count(args.next(), "sample count")?,
+                    "--warmup" => settings.warmup = parse_count(args.next(), "warmup count")?,
+                    "--history" => {
+                        history = args
+                            .next()
+                            .map(PathBuf::from)
+                            .ok_or_else(|| "missing history path".to_string())?
+                    }
+                    "--no-save" => save = false,
+                    other => {
+                        day = Some(
+                            other
+                                .parse()
+                                .map_err(|_| format!("invalid day: {other:?}"))?,
+                        )
+                    }
+                }
+            }
+            if settings.samples == 0 {
+                return Err("at least one sample is needed".to_string());
+            }
+            match (all, day) {
+                (true, Some(_)) => Err("--all doesn't take a day".to_string()),
+                (false, None) => Err("missing day".to_string()),
+                (_, day) => Ok(Command::Bench {
+                    day,
+                    settings,
+                    history,
+                    save,
+                }),
+            }
+        }
+        Some(other) => Err(format!("unknown command: {other:?}")),
+        None => Err("missing command".to_string()),
+    }
+}
+
+/// Reads the input together with a name to use in error messages.
+fn read_input(day: &Day, input: &Input) -> Result<(PathBuf, String), String> {
+    let path = match input {
+        Input::Default => day.input_path(),
+        Input::File(path) => PathBuf::from(path),
+        Input::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("stdin: {e}"))?;
+            return Ok((PathBuf::from("<stdin>"), input));
+        }
+    };
+    let input = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok((path, input))
+}
+
+fn print_answer(day: &Day, part: u8, answer: &str) {
+    if answer.contains('\n') {
+        println!("Day {:02} part {}:\n{}", day.day, part, answer.trim_end());
+    } else {
+        println!("Day {:02} part {}: {}", day.day, part, answer);
+    }
+}
+
+fn run(day: &Day, part: Option<u8>, input: &Input) -> Result<(), String> {
+    let (path, input) = read_input(day, input)?;
+    (day.check)(&input).map_err(|e| e.with_file(path).to_string())?;
+    match part {
+        Some(part) => {
+            let solver = day
+                .solver(part)
+                .ok_or_else(|| format!("day {} has no part {}", day.day, part))?;
+            print_answer(day, part, &solver(&input));
+        }
+        None => {
+            // the last day only has a single part
+            for part in 1..=2 {
+                if let Some(solver) = day.solver(part) {
+                    print_answer(day, part, &solver(&input));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn bench(
+    days: &[&Day],
+    settings: Settings,
+    history_path: &PathBuf,
+    save: bool,
+) -> Result<(), String> {
+    let mut history =
+        History::load(history_path).map_err(|e| format!("{}: {}", history_path.display(), e))?;
+    let mut records = Vec::new();
+    for day in days {
+        let (path, input) = read_input(day, &Input::Default)?;
+        records.extend((day.bench)(&input, &settings).map_err(|e| e.with_file(path).to_string())?);
+    }
+
+    let comparisons = bench::compare(&records, &history, REGRESSION_THRESHOLD);
+    println!(
+        "{:>3}  {:<5}  {:>9}  {:>9}  {:>9}  {:>7}",
+        "day", "stage", "median", "min", "previous", "change"
+    );
+    for comparison in &comparisons {
+        let record = &comparison.record;
+        let (previous, change) = match comparison.previous {
+            Some(previous) => (
+                bench::format_duration(previous),
+                format!(
+                    "{:+.0}%",
+                    (record.stats.median.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0
+                ),
+            ),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        println!(
+            "{:>3}  {:<5}  {:>9}  {:>9}  {:>9}  {:>7}{}",
+            record.day,
+            record.stage,
+            bench::format_duration(record.stats.median),
+            bench::format_duration(record.stats.min),
+            previous,
+            change,
+            if comparison.regression {
+                "  REGRESSION"
+            } else {
+                ""
+            }
+        );
+    }
+    let regressions = comparisons.iter().filter(|c| c.regression).count();
+    if regressions > 0 {
+        println!("{regressions} stage(s) slower than the previous run");
+    }
+
+    if save {
+        history.runs.push(Run::new(settings, records));
+        history
+            .save(history_path)
+            .map_err(|e| format!("{}: {}", history_path.display(), e))?;
+    }
+    Ok(())
+}
+
+fn main() {
+    let command = parse_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("error: {e}\n\n{USAGE}");
+        process::exit(2);
+    });
+    let result = match command {
+        Command::List => {
+            for day in days::DAYS {
+                let parts = if day.part2.is_some() { "1, 2" } else { "1" };
+                println!(
+                    "day {:2}  parts: {:4}  input: {}",
+                    day.day,
+                    parts,
+                    day.input_path().display()
+                );
+            }
+            Ok(())
+        }
+        Command::Run { day, part, input } => days::find(day)
+            .ok_or_else(|| format!("day {day} is not solved"))
+            .and_then(|day| run(day, part, &input)),
+        Command::RunAll => days::DAYS
+            .iter()
+            .try_for_each(|day| run(day, None, &Input::Default)),
+        Command::Bench {
+            day,
+            settings,
+            history,
+            save,
+        } => match day {
+            Some(day) => days::find(day)
+                .ok_or_else(|| format!("day {day} is not solved"))
+                .and_then(|day| bench(&[day], settings, &history, save)),
+            None => bench(
+                &days::DAYS.iter().collect::<Vec<_>>(),
+                settings,
+                &history,
+                save,
+            ),
+        },
+    };
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
@@ -1,4 +1,8 @@
 use std::io::{self, Read};
+use std::process;
+
+use advent_of_code_2018::days::day03::Claim;
+use advent_of_code_2018::parse::parse_lines;
 
 fn main() -> io::Result<()> {
     let mut input = String::new();
@@ -10,17 +14,12 @@ fn main() -> io::Result<()> {
         fabric.push([0; 1024]);
     }
 
-    for (x, y, w, h) in input.lines().filter(|line| !line.is_empty()).map(|line| {
-        // horrible parsing
-        let mut coords_iter = line.split(" @ ").nth(1).unwrap().split(": ");
-        let mut point_iter = coords_iter.next().unwrap().split(',');
-        let mut size_iter = coords_iter.next().unwrap().split('x');
-        let x = point_iter.next().unwrap().parse::<usize>().unwrap();
-        let y = point_iter.next().unwrap().parse::<usize>().unwrap();
-        let w = size_iter.next().unwrap().parse::<usize>().unwrap();
-        let h = size_iter.next().unwrap().parse::<usize>().unwrap();
-        (x, y, w, h)
-    }) {
+    let claims = parse_lines::<Claim>(&input).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(1);
+    });
+    for claim in &claims {
+        let (x, y, w, h) = (claim.x, claim.y, claim.width, claim.height);
         for row in fabric.iter_mut().skip(x).take(w) {
             for f in row.iter_mut().skip(y).take(h) {
                 *f += 1;
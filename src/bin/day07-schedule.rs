@@ -0,0 +1,79 @@
+use std::io::{self, Read};
+use std::process;
+
+use advent_of_code_2018::days::day07::{self, Dependency, Scheduler, TieBreak};
+use advent_of_code_2018::parse::parse_lines;
+
+const USAGE: &str = "\
+usage: day07-schedule [<workers> [<base time>]] [--longest-first] < input
+       day07-schedule --dot < input
+       day07-schedule --orderings <n> [--longest-first] < input";
+
+fn number(arg: Option<String>) -> usize {
+    let arg = arg.unwrap_or_default();
+    arg.parse().unwrap_or_else(|_| {
+        eprintln!("error: invalid number: {arg:?}\n\n{USAGE}");
+        process::exit(2);
+    })
+}
+
+/// Prints the second by second schedule and the critical path of the steps, the graph in
+/// the DOT format or the first few orders a single worker could do the steps in.
+fn main() -> io::Result<()> {
+    let mut scheduler = Scheduler::new(5, 60);
+    let mut numbers = Vec::new();
+    let (mut dot, mut orderings) = (false, None);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--longest-first" => scheduler.tie_break = TieBreak::LongestFirst,
+            "--dot" => dot = true,
+            "--orderings" => orderings = Some(number(args.next())),
+            _ => numbers.push(number(Some(arg))),
+        }
+    }
+    match numbers[..] {
+        [] => {}
+        [workers] if workers > 0 => scheduler.workers = workers,
+        [workers, base_time] if workers > 0 => {
+            scheduler.workers = workers;
+            scheduler.base_time = base_time;
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    }
+
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    let entries = parse_lines::<Dependency>(&input).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(1);
+    });
+
+    if dot {
+        print!("{}", day07::dot(&entries));
+        return Ok(());
+    }
+    let schedule = scheduler.run(&entries).unwrap_or_else(|cycle| {
+        eprintln!("error: dependency cycle {cycle}");
+        process::exit(1);
+    });
+    if let Some(count) = orderings {
+        for ordering in scheduler.orderings(&entries).take(count) {
+            println!("{ordering}");
+        }
+        return Ok(());
+    }
+    print!("{}", schedule.table());
+    println!();
+    println!("completion time: {}s", schedule.completion_time());
+    // there's no cycle, the schedule would have failed
+    println!(
+        "critical path: {}",
+        scheduler.critical_path(&entries).unwrap()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use std::fs;
+use std::process;
+
+use advent_of_code_2018::bench::{format_duration, measure, Settings};
+use advent_of_code_2018::days::day09::Game;
+
+const USAGE: &str = "usage: day09-bench [<input>]";
+
+/// Times the marble game on the linked circle against a rotated `VecDeque`, for the input
+/// game and the 100 times longer one.
+fn main() {
+    let path = match std::env::args().nth(1) {
+        Some(arg) if arg.starts_with('-') => {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+        Some(path) => path,
+        None => concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/day09.txt").to_string(),
+    };
+    let input = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("error: {path}: {e}");
+        process::exit(1);
+    });
+    let game = input
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .parse::<Game>()
+        .unwrap_or_else(|e| {
+            eprintln!("error: {}", e.with_file(&path));
+            process::exit(1);
+        });
+
+    let settings = Settings::default();
+    println!("{:<6} {:>12} {:>12}", "game", "linked", "deque");
+    for times in [1, 100] {
+        let rules = game.rules(times);
+        let linked = measure(&settings, || rules.play().high_score());
+        let deque = measure(&settings, || rules.play_with_deque().high_score());
+        println!(
+            "{:<6} {:>12} {:>12}",
+            format!("x{times}"),
+            format_duration(linked.median),
+            format_duration(deque.median)
+        );
+    }
+}
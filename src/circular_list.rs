@@ -0,0 +1,430 @@
+use std::fmt;
+
+/// Values per block before it's split in two halves.
+const BLOCK: usize = 64;
+
+/// A run of consecutive values, linked clockwise and counter-clockwise to its neighbours.
+#[derive(Clone)]
+struct Block {
+    values: Vec<u32>,
+    next: u32,
+    prev: u32,
+}
+
+/// Circular list of the values `0..capacity` with a cursor, stored as a ring of blocks.
+///
+/// Each block holds between `BLOCK / 2` and `2 * BLOCK` values (only a lone block may have
+/// fewer), so moving the cursor hops whole blocks at a time instead of single values.
+#[derive(Clone)]
+pub struct CircularList {
+    blocks: Vec<Block>,
+    /// Slots of merged blocks, reused when a block is split.
+    free: Vec<u32>,
+    present: Vec<bool>,
+    /// The cursor is `blocks[block].values[offset]`.
+    block: u32,
+    offset: usize,
+    len: usize,
+}
+
+impl CircularList {
+    /// List holding just `first`, any other value below `capacity` can be inserted later.
+    pub fn new(first: usize, capacity: usize) -> Self {
+        assert!(
+            capacity <= u32::MAX as usize,
+            "capacity {capacity} doesn't fit in u32"
+        );
+        assert!(first < capacity, "{first} is out of capacity");
+        let mut present = vec![false; capacity];
+        present[first] = true;
+        Self {
+            blocks: vec![Block {
+                values: vec![first as u32],
+                next: 0,
+                prev: 0,
+            }],
+            free: Vec::new(),
+            present,
+            block: 0,
+            offset: 0,
+            len: 1,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.present.len()
+    }
+
+    /// Value under the cursor, meaningless for an empty list.
+    pub fn current(&self) -> usize {
+        self.blocks[self.block as usize]
+            .values
+            .get(self.offset)
+            .map_or(0, |value| *value as usize)
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        self.present.get(value).is_some_and(|present| *present)
+    }
+
+    /// Moves the cursor `k` values clockwise, or counter-clockwise for negative `k`.
+    ///
+    /// Whole turns are skipped and it goes whichever way round is shorter, a block at a time,
+    /// so it costs `O(1 + min(k mod len, len - k mod len) / BLOCK)`. Anything up to half a
+    /// block away (the marble game only ever moves 1 or 7) takes constant time.
+    pub fn rotate(&mut self, k: isize) {
+        if self.is_empty() {
+            return;
+        }
+        let steps = k.rem_euclid(self.len as isize) as usize;
+        if steps <= self.len / 2 {
+            self.forward(steps);
+        } else {
+            self.backward(self.len - steps);
+        }
+    }
+
+    fn forward(&mut self, mut steps: usize) {
+        loop {
+            let after = self.blocks[self.block as usize].values.len() - 1 - self.offset;
+            if steps <= after {
+                self.offset += steps;
+                return;
+            }
+            steps -= after + 1;
+            self.block = self.blocks[self.block as usize].next;
+            self.offset = 0;
+        }
+    }
+
+    fn backward(&mut self, mut steps: usize) {
+        loop {
+            if steps <= self.offset {
+                self.offset -= steps;
+                return;
+            }
+            steps -= self.offset + 1;
+            self.block = self.blocks[self.block as usize].prev;
+            self.offset = self.blocks[self.block as usize].values.len() - 1;
+        }
+    }
+
+    /// Inserts `value` clockwise next to the cursor and moves the cursor onto it.
+    pub fn insert_after(&mut self, value: usize) {
+        assert!(
+            value < self.capacity() && !self.contains(value),
+            "{value} can't be inserted"
+        );
+        self.present[value] = true;
+        self.len += 1;
+        let block = self.block;
+        let values = &mut self.blocks[block as usize].values;
+        if values.is_empty() {
+            values.push(value as u32);
+            self.offset = 0;
+            return;
+        }
+        self.offset += 1;
+        values.insert(self.offset, value as u32);
+        if values.len() > 2 * BLOCK {
+            self.split(block, BLOCK);
+        }
+    }
+
+    /// Removes the value under the cursor and moves the cursor clockwise to the next one.
+    pub fn remove(&mut self) -> usize {
+        assert!(!self.is_empty(), "nothing to remove");
+        let block = self.block;
+        let removed = self.blocks[block as usize].values.remove(self.offset) as usize;
+        self.present[removed] = false;
+        self.len -= 1;
+        let next = self.blocks[block as usize].next;
+        if self.offset == self.blocks[block as usize].values.len() {
+            self.block = next;
+            self.offset = 0;
+        }
+        if next != block && self.blocks[block as usize].values.len() < BLOCK / 2 {
+            self.merge_next(block);
+        }
+        removed
+    }
+
+    /// Moves the values of `block` from `at` on into a new block right after it.
+    fn split(&mut self, block: u32, at: usize) {
+        let values = self.blocks[block as usize].values.split_off(at);
+        let next = self.blocks[block as usize].next;
+        let new = Block {
+            values,
+            next,
+            prev: block,
+        };
+        let new = match self.free.pop() {
+            Some(slot) => {
+                self.blocks[slot as usize] = new;
+                slot
+            }
+            None => {
+                self.blocks.push(new);
+                (self.blocks.len() - 1) as u32
+            }
+        };
+        self.blocks[block as usize].next = new;
+        self.blocks[next as usize].prev = new;
+        if self.block == block && self.offset >= at {
+            self.block = new;
+            self.offset -= at;
+        }
+    }
+
+    /// Appends the next block to `block`, splitting it again in halves if it gets too big.
+    fn merge_next(&mut self, block: u32) {
+        let next = self.blocks[block as usize].next;
+        let Block {
+            values: moved,
+            next: after,
+            ..
+        } = std::mem::replace(
+            &mut self.blocks[next as usize],
+            Block {
+                values: Vec::new(),
+                next,
+                prev: next,
+            },
+        );
+        self.free.push(next);
+        let values = &mut self.blocks[block as usize].values;
+        if self.block == next {
+            self.block = block;
+            self.offset += values.len();
+        }
+        values.extend(moved);
+        let len = values.len();
+        self.blocks[block as usize].next = after;
+        self.blocks[after as usize].prev = block;
+        if len > 2 * BLOCK {
+            self.split(block, len / 2);
+        }
+    }
+
+    /// All the values clockwise starting with `head`, which has to be in the list.
+    pub fn iter_from(&self, head: usize) -> impl Iterator<Item = usize> + '_ {
+        assert!(self.contains(head), "{head} isn't in the list");
+        let mut block = self.block;
+        while !self.blocks[block as usize].values.contains(&(head as u32)) {
+            block = self.blocks[block as usize].next;
+        }
+        let values = &self.blocks[block as usize].values;
+        let offset = values
+            .iter()
+            .position(|value| *value == head as u32)
+            .unwrap();
+        // the following blocks wrap around to the start of the head's block
+        let blocks =
+            std::iter::successors(Some(block), |block| Some(self.blocks[*block as usize].next))
+                .skip(1)
+                .map(|block| &self.blocks[block as usize].values[..]);
+        values[offset..]
+            .iter()
+            .chain(blocks.flatten())
+            .take(self.len)
+            .map(|value| *value as usize)
+    }
+
+    /// Prints the values starting with `head` instead of the cursor.
+    pub fn debug_from(&self, head: usize) -> impl fmt::Debug + '_ {
+        DebugFrom { list: self, head }
+    }
+}
+
+struct DebugFrom<'a> {
+    list: &'a CircularList,
+    head: usize,
+}
+
+impl fmt::Debug for DebugFrom<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.list.is_empty() {
+            return write!(f, "[]");
+        }
+        f.debug_list()
+            .entries(self.list.iter_from(self.head))
+            .finish()
+    }
+}
+
+impl fmt::Debug for CircularList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "[]");
+        }
+        self.debug_from(self.current()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Sizes of the blocks clockwise from the cursor's one.
+    fn block_sizes(list: &CircularList) -> Vec<usize> {
+        let mut sizes = Vec::new();
+        let mut block = list.block;
+        loop {
+            let Block { values, next, .. } = &list.blocks[block as usize];
+            sizes.push(values.len());
+            assert_eq!(list.blocks[*next as usize].prev, block);
+            block = *next;
+            if block == list.block {
+                return sizes;
+            }
+        }
+    }
+
+    #[test]
+    fn test_list() {
+        let mut list = CircularList::new(0, 10);
+        for value in 1..5 {
+            list.insert_after(value);
+        }
+        assert_eq!(format!("{list:?}"), "[4, 0, 1, 2, 3]");
+        assert_eq!(format!("{:?}", list.debug_from(0)), "[0, 1, 2, 3, 4]");
+        assert!(list.contains(3) && !list.contains(5) && !list.contains(10));
+
+        list.rotate(-2);
+        assert_eq!(list.current(), 2);
+        list.rotate(13);
+        assert_eq!(list.current(), 0);
+        assert_eq!(list.remove(), 0);
+        assert_eq!(list.current(), 1);
+        assert!(!list.contains(0));
+        assert_eq!(list.iter_from(4).collect::<Vec<_>>(), vec![4, 1, 2, 3]);
+
+        list.insert_after(0);
+        assert_eq!(format!("{:?}", list.debug_from(4)), "[4, 1, 0, 2, 3]");
+    }
+
+    #[test]
+    fn test_rotate_past_len() {
+        let mut list = CircularList::new(0, 7);
+        for value in 1..7 {
+            list.insert_after(value);
+        }
+        // the cursor is on 6, clockwise order is 0..7
+        for k in -30..=30 {
+            let mut rotated = list.clone();
+            rotated.rotate(k);
+            assert_eq!(rotated.current(), (6 + k).rem_euclid(7) as usize, "{k}");
+        }
+        list.rotate(7 * 1000 + 3);
+        assert_eq!(list.current(), 2);
+        list.rotate(-(7 * 1000 + 3));
+        assert_eq!(list.current(), 6);
+    }
+
+    #[test]
+    fn test_random_against_vec() {
+        let mut rng = StdRng::seed_from_u64(47);
+        let capacity = 2_000;
+        let mut list = CircularList::new(0, capacity);
+        // clockwise from index 0, the cursor is `values[cursor]`
+        let mut values = vec![0];
+        let mut cursor = 0;
+        for round in 0..20_000 {
+            let len = values.len() as isize;
+            match rng.gen_range(0..10) {
+                0..=3 if !values.is_empty() => {
+                    let k = rng.gen_range(-3 * len..=3 * len);
+                    list.rotate(k);
+                    cursor = (cursor as isize + k).rem_euclid(len) as usize;
+                }
+                4..=6 if !values.is_empty() => {
+                    assert_eq!(list.remove(), values.remove(cursor));
+                    if cursor == values.len() {
+                        cursor = 0;
+                    }
+                }
+                _ => {
+                    let value = rng.gen_range(0..capacity);
+                    if !values.contains(&value) {
+                        list.insert_after(value);
+                        if values.is_empty() {
+                            values.push(value);
+                            cursor = 0;
+                        } else {
+                            cursor += 1;
+                            values.insert(cursor, value);
+                        }
+                    }
+                }
+            }
+            assert_eq!(list.len(), values.len());
+            if values.is_empty() {
+                continue;
+            }
+            assert_eq!(list.current(), values[cursor], "{round}");
+            if round % 100 == 0 {
+                let expected: Vec<usize> = values[cursor..]
+                    .iter()
+                    .chain(&values[..cursor])
+                    .copied()
+                    .collect();
+                assert_eq!(list.iter_from(values[cursor]).collect::<Vec<_>>(), expected);
+                let sizes = block_sizes(&list);
+                assert_eq!(sizes.iter().sum::<usize>(), values.len());
+                assert!(
+                    sizes.len() == 1
+                        || sizes
+                            .iter()
+                            .all(|size| (BLOCK / 2..=2 * BLOCK).contains(size)),
+                    "{sizes:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_across_blocks() {
+        let len = 100 * BLOCK;
+        let mut list = CircularList::new(0, len);
+        for value in 1..len {
+            list.insert_after(value);
+        }
+        list.rotate(1);
+        assert_eq!(list.current(), 0);
+        let blocks = block_sizes(&list).len();
+        assert!((50..=100).contains(&blocks), "{blocks}");
+        for k in [1, 7, -7, BLOCK as isize * 10 + 3, -(len as isize) * 3 - 5] {
+            let before = list.current();
+            list.rotate(k);
+            assert_eq!(
+                list.current(),
+                (before as isize + k).rem_euclid(len as isize) as usize
+            );
+        }
+    }
+
+    #[test]
+    fn test_single() {
+        let mut list = CircularList::new(3, 4);
+        assert!(list.contains(3) && !list.contains(0));
+        list.rotate(5);
+        assert_eq!(list.current(), 3);
+        assert_eq!(list.remove(), 3);
+        assert!(list.is_empty() && !list.contains(3));
+        assert_eq!(format!("{list:?}"), "[]");
+        list.insert_after(1);
+        assert_eq!((list.len(), list.current()), (1, 1));
+        list.insert_after(3);
+        assert_eq!(format!("{list:?}"), "[3, 1]");
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+pub struct Day01;
+
+impl Problem for Day01 {
+    type Input = VecFromLines<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        input.iter().sum()
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        let mut freq = 0;
+        let mut seen = HashSet::new();
+        loop {
+            for n in input {
+                freq += n;
+                if seen.contains(&freq) {
+                    return freq;
+                }
+                seen.insert(freq);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_helpers::scaffold::{solve_part1, solve_part2};
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day01>("+1\n-2\n+3\n+1"), 3);
+        assert_eq!(solve_part2::<Day01>("+1\n-2\n+3\n+1"), 2);
+        assert_eq!(solve_part2::<Day01>("+3\n+3\n+4\n-2\n-4"), 10);
+        assert_eq!(solve_part2::<Day01>("-6\n+3\n+8\n+5\n-6"), 5);
+    }
+}
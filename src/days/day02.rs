@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+pub struct Day02;
+
+fn checksum(ids: &[String]) -> usize {
+    let (twos, threes) =
+        ids.iter()
+            .filter(|line| !line.is_empty())
+            .fold((0, 0), |(two, three), line| {
+                let mut two_count = 0;
+                let mut three_count = 0;
+                let mut counts = HashMap::new();
+                for chr in line.chars() {
+                    match counts.entry(chr).and_modify(|x| *x += 1).or_insert(1) {
+                        2 => {
+                            two_count += 1;
+                        }
+                        3 => {
+                            two_count -= 1;
+                            three_count += 1;
+                        }
+                        4 => {
+                            three_count -= 1;
+                        }
+                        _ => {}
+                    }
+                }
+                (
+                    if two_count > 0 { two + 1 } else { two },
+                    if three_count > 0 { three + 1 } else { three },
+                )
+            });
+    twos * threes
+}
+
+fn common_letters(ids: &[String]) -> String {
+    for i in 0..ids.len() {
+        for j in i..ids.len() {
+            let diffs = ids[i]
+                .chars()
+                .zip(ids[j].chars())
+                .filter(|(c1, c2)| c1 != c2)
+                .count();
+            if diffs == 1 {
+                return ids[i]
+                    .chars()
+                    .zip(ids[j].chars())
+                    .filter(|(c1, c2)| c1 == c2)
+                    .map(|(c, _)| c)
+                    .collect();
+            }
+        }
+    }
+    panic!("no ids differing by exactly one character");
+}
+
+impl Problem for Day02 {
+    type Input = VecFromLines<String>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        checksum(input)
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        common_letters(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_helpers::scaffold::{solve_part1, solve_part2};
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(
+            solve_part1::<Day02>(include_str!("../../inputs/day02a-example.txt")),
+            12
+        );
+        assert_eq!(
+            solve_part2::<Day02>(include_str!("../../inputs/day02b-example.txt")),
+            "fgij"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, ParseError};
+
+pub struct Day03;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Claim {
+    pub id: usize,
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl FromStr for Claim {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expected = |part, what| move || ParseError::at(s, part, format!("expected {what}"));
+        let (id, coords) = s.split_once(" @ ").ok_or_else(expected(s, "`#<id> @ `"))?;
+        let (point, size) = coords
+            .split_once(": ")
+            .ok_or_else(expected(coords, "`<x>,<y>: `"))?;
+        let (x, y) = point
+            .split_once(',')
+            .ok_or_else(expected(point, "`<x>,<y>`"))?;
+        let (width, height) = size
+            .split_once('x')
+            .ok_or_else(expected(size, "`<width>x<height>`"))?;
+        Ok(Self {
+            id: parse_field(s, id.strip_prefix('#').ok_or_else(expected(id, "`#`"))?)?,
+            x: parse_field(s, x)?,
+            y: parse_field(s, y)?,
+            width: parse_field(s, width)?,
+            height: parse_field(s, height)?,
+        })
+    }
+}
+
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Range(usize, usize, usize);
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Rect(Range, Range, usize);
+
+#[derive(Debug)]
+enum RangeSweep<'a> {
+    On(&'a Range),
+    Off(&'a Range),
+}
+
+use self::RangeSweep::{Off, On};
+
+fn overlapping_area(input: &[Claim]) -> usize {
+    let mut idx = 0; // a dummy solution to the problem of ranges being equal
+
+    let claims: Vec<Rect> = input
+        .iter()
+        .map(|claim| {
+            let dim1 = claim.x;
+            let dim2 = claim.y;
+            let dim1_end = dim1 + claim.width;
+            let dim2_end = dim2 + claim.height;
+            idx += 1;
+            let range1 = Range(dim1, dim1_end, idx);
+            idx += 1;
+            let range2 = Range(dim2, dim2_end, idx);
+            idx += 1;
+            Rect(range1, range2, idx)
+        })
+        .collect();
+
+    // prepare edge points for first dimension
+    let mut all_first_dimens = Vec::with_capacity(claims.len() * 2);
+    let mut sweeps: HashMap<usize, Vec<RangeSweep>> = HashMap::new();
+    for claim in &claims {
+        all_first_dimens.push((claim.0).0);
+        all_first_dimens.push((claim.0).1);
+        sweeps.entry((claim.0).0).or_default().push(On(&claim.1));
+        sweeps.entry((claim.0).1).or_default().push(Off(&claim.1));
+    }
+    all_first_dimens.sort_unstable();
+    all_first_dimens.dedup();
+
+    let mut overlapping = 0; // counter for overlapping area
+
+    // currently active ranges (in terms of first dimension)
+    let mut active_ranges: HashSet<&Range> = HashSet::new();
+    for i in 0..all_first_dimens.len() {
+        if i > 0 && !active_ranges.is_empty() {
+            // something is active -> let's check the other dimension
+            let hight = all_first_dimens[i] - all_first_dimens[i - 1];
+
+            // prepare edge points for second dimension
+            let mut strip_dimens = Vec::with_capacity(active_ranges.len() * 2);
+            let mut strip_sweeps: HashMap<usize, Vec<RangeSweep>> = HashMap::new();
+            let mut strip_active_ranges: HashSet<&Range> = HashSet::new();
+            let mut strip_overlapping = 0;
+            for range in active_ranges.iter() {
+                strip_dimens.push(range.0);
+                strip_dimens.push(range.1);
+                strip_sweeps.entry(range.0).or_default().push(On(range));
+                strip_sweeps.entry(range.1).or_default().push(Off(range));
+            }
+            strip_dimens.sort_unstable();
+            strip_dimens.dedup();
+
+            for j in 0..strip_dimens.len() {
+                if j > 0 && strip_active_ranges.len() > 1 {
+                    // more then 1 range is active -> we have an overlap!
+                    let current_overlap = strip_dimens[j] - strip_dimens[j - 1];
+                    strip_overlapping += hight * current_overlap;
+                }
+                // process changes for current edge point (in strip = second dimension)
+                for range_sweep in strip_sweeps.get(&strip_dimens[j]).unwrap() {
+                    match range_sweep {
+                        On(range) => strip_active_ranges.insert(range),
+                        Off(range) => strip_active_ranges.remove(range),
+                    };
+                }
+            }
+            overlapping += strip_overlapping;
+        }
+
+        // process changes for current edge point (first dimension)
+        for range_sweep in sweeps.get(&all_first_dimens[i]).unwrap() {
+            match range_sweep {
+                On(range) => active_ranges.insert(range),
+                Off(range) => active_ranges.remove(range),
+            };
+        }
+    }
+
+    overlapping
+}
+
+/// Id of the claim which doesn't overlap any other, the lowest one if there are more.
+fn not_overlapping_claim(claims: &[Claim]) -> usize {
+    let mut not_overlapping = HashSet::new();
+    let mut fabric: Vec<[usize; 1024]> = Vec::with_capacity(1024);
+    for _ in 0..1024 {
+        fabric.push([0; 1024]);
+    }
+
+    for claim in claims {
+        let idx = claim.id;
+        not_overlapping.insert(idx);
+
+        for row in fabric.iter_mut().skip(claim.x).take(claim.width) {
+            for f in row.iter_mut().skip(claim.y).take(claim.height) {
+                if *f != 0 {
+                    not_overlapping.remove(&idx);
+                    not_overlapping.remove(f);
+                } else {
+                    *f = idx;
+                }
+            }
+        }
+    }
+
+    not_overlapping
+        .into_iter()
+        .min()
+        .expect("there should be a claim without overlaps")
+}
+
+impl Problem for Day03 {
+    type Input = VecFromLines<Claim>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        overlapping_area(input)
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        not_overlapping_claim(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use crate::parse::parse_lines;
+    use aoc_helpers::scaffold::{solve_part1, solve_part2};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const SAMPLE: &str = include_str!("../../inputs/day03-example.txt");
+
+    /// Marks every square inch, returns the overlapping area and all claims without overlaps.
+    fn brute_force(claims: &[Claim]) -> (usize, Vec<usize>) {
+        let mut fabric = vec![vec![0; 1000]; 1000];
+        for claim in claims {
+            for row in fabric.iter_mut().skip(claim.x).take(claim.width) {
+                for f in row.iter_mut().skip(claim.y).take(claim.height) {
+                    *f += 1;
+                }
+            }
+        }
+        let overlapping = fabric.iter().flatten().filter(|f| **f > 1).count();
+        let intact = claims
+            .iter()
+            .filter(|claim| {
+                fabric
+                    .iter()
+                    .skip(claim.x)
+                    .take(claim.width)
+                    .all(|row| row.iter().skip(claim.y).take(claim.height).all(|f| *f == 1))
+            })
+            .map(|claim| claim.id)
+            .collect();
+        (overlapping, intact)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "#123 @ 3,2: 5x4".parse::<Claim>().unwrap(),
+            Claim {
+                id: 123,
+                x: 3,
+                y: 2,
+                width: 5,
+                height: 4
+            }
+        );
+        assert_eq!("#123 @ 3,2 5x4".parse::<Claim>().unwrap_err().column, 8);
+        assert_eq!("#123 @ 3,y: 5x4".parse::<Claim>().unwrap_err().column, 10);
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day03>(SAMPLE), 4);
+        assert_eq!(solve_part2::<Day03>(SAMPLE), 3);
+    }
+
+    #[test]
+    fn test_lowest_intact_claim() {
+        let claims =
+            parse_lines::<Claim>("#7 @ 0,0: 2x2\n#5 @ 5,5: 1x1\n#9 @ 8,8: 3x3\n#2 @ 1,1: 2x2\n")
+                .unwrap();
+        assert_eq!(not_overlapping_claim(&claims), 5);
+    }
+
+    #[test]
+    fn test_random_claims() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..50 {
+            let claims = parse_lines::<Claim>(&gen::claims(&mut rng, 30, 80)).unwrap();
+            let (overlapping, intact) = brute_force(&claims);
+            assert_eq!(overlapping_area(&claims), overlapping);
+            if let Some(lowest) = intact.iter().min() {
+                assert_eq!(not_overlapping_claim(&claims), *lowest);
+            }
+        }
+    }
+}
@@ -0,0 +1,413 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, parse_lines, ParseError};
+
+pub struct Day04;
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Action {
+    Start(usize),
+    Down,
+    Up,
+}
+
+use self::Action::*;
+
+/// Time of a log entry on the Gregorian calendar.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DateTime {
+    year: usize,
+    month: usize,
+    day: usize,
+    hour: usize,
+    minute: usize,
+}
+
+/// Days in each month of a common year, January first.
+pub const DAYS_IN_MONTH: [usize; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+fn is_leap(year: usize) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: usize, month: usize) -> usize {
+    if month == 2 && is_leap(year) {
+        29
+    } else {
+        DAYS_IN_MONTH[month - 1]
+    }
+}
+
+impl DateTime {
+    /// Days since the first of January of year 0.
+    fn days(&self) -> usize {
+        // leap years before this one, year 0 included
+        let leap_years = self.year.div_ceil(4) - self.year.div_ceil(100) + self.year.div_ceil(400);
+        let months: usize = (1..self.month)
+            .map(|month| days_in_month(self.year, month))
+            .sum();
+        self.year * 365 + leap_years + months + self.day - 1
+    }
+
+    /// Minutes since midnight of the first of January of year 0.
+    fn timestamp(&self) -> usize {
+        (self.days() * 24 + self.hour) * 60 + self.minute
+    }
+
+    /// Day (as counted by `days`) of the midnight hour anything happening now is part of,
+    /// guards often come in during the hour before.
+    fn shift_day(&self) -> usize {
+        self.days() + usize::from(self.hour == 23)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    time: DateTime,
+    action: Action,
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"\[(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2}) (?P<hour>\d{2}):(?P<minute>\d{2})\] (?P<message>.*)").unwrap();
+            static ref MESSAGE_RE: Regex = Regex::new(r"Guard #(?P<num>\d+) begins shift").unwrap();
+        }
+
+        let caps = RE
+            .captures(s)
+            .ok_or_else(|| ParseError::at(s, s, "expected `[YYYY-MM-DD hh:mm] <message>`"))?;
+        let time = DateTime {
+            year: parse_field(s, &caps["year"])?,
+            month: parse_field(s, &caps["month"])?,
+            day: parse_field(s, &caps["day"])?,
+            hour: parse_field(s, &caps["hour"])?,
+            minute: parse_field(s, &caps["minute"])?,
+        };
+        if !(1..=12).contains(&time.month) {
+            return Err(ParseError::at(s, &caps["month"], "no such month"));
+        }
+        if !(1..=days_in_month(time.year, time.month)).contains(&time.day) {
+            return Err(ParseError::at(s, &caps["day"], "no such day in the month"));
+        }
+        if time.hour > 23 || time.minute > 59 {
+            return Err(ParseError::at(s, &caps["hour"], "no such time of day"));
+        }
+        Ok(Entry {
+            time,
+            action: match &caps["message"] {
+                "falls asleep" => Down,
+                "wakes up" => Up,
+                other => {
+                    let matches = MESSAGE_RE
+                        .captures(other)
+                        .ok_or_else(|| ParseError::at(s, other, "unknown message"))?;
+                    Start(parse_field(s, &matches["num"])?)
+                }
+            },
+        })
+    }
+}
+
+/// A guard asleep from `start` up to but not including `end`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Nap {
+    pub guard: usize,
+    pub start: DateTime,
+    pub end: DateTime,
+}
+
+/// Every nap in chronological order, each one is the guard's on duty for that night.
+///
+/// Error comes with the index of the line causing it.
+fn naps(entries: &[Entry]) -> Result<Vec<Nap>, (usize, &'static str)> {
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|idx| entries[*idx].time);
+
+    // guard on duty with the day of their shift, and since when they're asleep
+    let mut shift: Option<(usize, usize)> = None;
+    let mut asleep: Option<(usize, DateTime)> = None;
+    let mut naps = Vec::new();
+    for idx in order {
+        let time = entries[idx].time;
+        match (&entries[idx].action, shift, asleep) {
+            (Start(_), _, Some(_)) => {
+                return Err((idx, "shift begins while the previous guard is asleep"));
+            }
+            (Start(guard), _, None) => shift = Some((*guard, time.shift_day())),
+            (Down, None, _) => return Err((idx, "falls asleep before any shift begins")),
+            (Down, Some((_, day)), _) if time.shift_day() != day => {
+                return Err((idx, "falls asleep on another night than the guard's shift"));
+            }
+            (Down, _, Some(_)) => return Err((idx, "falls asleep while already asleep")),
+            (Down, Some(_), None) => asleep = Some((idx, time)),
+            (Up, _, None) => return Err((idx, "wakes up without falling asleep")),
+            (Up, None, Some(_)) => unreachable!("asleep without a shift"),
+            (Up, Some((guard, _)), Some((_, start))) => {
+                naps.push(Nap {
+                    guard,
+                    start,
+                    end: time,
+                });
+                asleep = None;
+            }
+        }
+    }
+    match asleep {
+        Some((idx, _)) => Err((idx, "falls asleep and never wakes up")),
+        None => Ok(naps),
+    }
+}
+
+pub fn check(input: &str) -> Result<(), ParseError> {
+    let entries = parse_lines::<Entry>(input)?;
+    naps(&entries).map(|_| ()).map_err(|(idx, message)| {
+        ParseError::new(message).with_line(idx + 1, input.lines().nth(idx).unwrap())
+    })
+}
+
+/// How many times each guard was asleep during each minute of the midnight hour.
+fn sleep_schedules(entries: &[Entry]) -> HashMap<usize, [usize; 60]> {
+    let naps = naps(entries).unwrap_or_else(|(_, message)| panic!("{message}"));
+    let mut sleep: HashMap<usize, [usize; 60]> = HashMap::new();
+    for nap in naps {
+        let schedule = sleep.entry(nap.guard).or_insert([0; 60]);
+        for minute in nap.start.timestamp()..nap.end.timestamp() {
+            // naps starting before midnight only count from 00:00
+            if let Some(count) = schedule.get_mut(minute % (24 * 60)) {
+                *count += 1;
+            }
+        }
+    }
+    sleep
+}
+
+fn sleepiest_minute(schedule: &[usize; 60]) -> (usize, usize) {
+    let mut max_sleep_minute = 0;
+    let mut max_sleep_count = 0;
+    for (minute, sleep_count) in schedule.iter().enumerate() {
+        if max_sleep_count < *sleep_count {
+            max_sleep_minute = minute;
+            max_sleep_count = *sleep_count;
+        }
+    }
+    (max_sleep_minute, max_sleep_count)
+}
+
+impl Problem for Day04 {
+    type Input = VecFromLines<Entry>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        let sleep = sleep_schedules(input);
+        let (max_sleeper, schedule) = sleep
+            .iter()
+            .max_by_key(|(_, schedule)| schedule.iter().sum::<usize>())
+            .unwrap();
+        max_sleeper * sleepiest_minute(schedule).0
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        let sleep = sleep_schedules(input);
+        let (max_sleeper, (max_sleep_minute, _)) = sleep
+            .iter()
+            .map(|(guard, schedule)| (guard, sleepiest_minute(schedule)))
+            .max_by_key(|(_, (_, count))| *count)
+            .unwrap();
+        max_sleeper * max_sleep_minute
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use aoc_helpers::scaffold::{solve_part1, solve_part2};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    const SAMPLE: &str = include_str!("../../inputs/day04-example.txt");
+
+    /// Works on the raw log (which sorts chronologically as text), returns all the answers
+    /// for both strategies as there can be ties.
+    fn brute_force(input: &str) -> (HashSet<usize>, HashSet<usize>) {
+        let mut lines: Vec<&str> = input.lines().collect();
+        lines.sort_unstable();
+        let mut sleep: HashMap<usize, [usize; 60]> = HashMap::new();
+        let (mut guard, mut asleep) = (0, 0);
+        for line in lines {
+            let minute: usize = line[15..17].parse().unwrap();
+            if let Some((_, rest)) = line.split_once('#') {
+                guard = rest.split(' ').next().unwrap().parse().unwrap();
+            } else if line.ends_with("falls asleep") {
+                asleep = minute;
+            } else {
+                let schedule = sleep.entry(guard).or_insert([0; 60]);
+                for count in &mut schedule[asleep..minute] {
+                    *count += 1;
+                }
+            }
+        }
+
+        // guards with the most sleep in total, each with all of their sleepiest minutes
+        let most_sleep = sleep
+            .values()
+            .map(|schedule| schedule.iter().sum::<usize>())
+            .max()
+            .unwrap();
+        let part1 = sleep
+            .iter()
+            .filter(|(_, schedule)| schedule.iter().sum::<usize>() == most_sleep)
+            .flat_map(|(guard, schedule)| {
+                let best = *schedule.iter().max().unwrap();
+                (0..60)
+                    .filter(move |minute| schedule[*minute] == best)
+                    .map(move |minute| guard * minute)
+            })
+            .collect();
+        // any guard and minute with the highest count
+        let best = *sleep.values().flatten().max().unwrap();
+        let part2 = sleep
+            .iter()
+            .flat_map(|(guard, schedule)| {
+                (0..60)
+                    .filter(move |minute| schedule[*minute] == best)
+                    .map(move |minute| guard * minute)
+            })
+            .collect();
+        (part1, part2)
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = "[1518-11-01 00:05] falls awake"
+            .parse::<Entry>()
+            .unwrap_err();
+        assert_eq!(error.column, 20);
+        assert_eq!(error.message, "unknown message");
+    }
+
+    #[test]
+    fn test_calendar() {
+        let time = |s: &str| format!("[{s}] wakes up").parse::<Entry>().map(|e| e.time);
+        assert!(time("1520-02-29 00:00").is_ok() && time("1600-02-29 00:00").is_ok());
+        for (s, column, message) in [
+            ("1518-02-29 00:00", 10, "no such day in the month"),
+            ("1700-02-29 00:00", 10, "no such day in the month"),
+            ("1518-04-31 00:00", 10, "no such day in the month"),
+            ("1518-13-01 00:00", 7, "no such month"),
+            ("1518-11-01 24:00", 13, "no such time of day"),
+        ] {
+            let error = time(s).unwrap_err();
+            assert_eq!(
+                (error.column, error.message.as_str()),
+                (column, message),
+                "{s}"
+            );
+        }
+
+        let new_year = time("1519-01-01 00:10").unwrap();
+        assert_eq!(
+            time("1518-12-31 00:10").unwrap().days() + 1,
+            new_year.days()
+        );
+        assert_eq!(
+            time("1518-12-31 23:50").unwrap().shift_day(),
+            new_year.days()
+        );
+        assert_eq!(
+            time("1601-01-01 00:00").unwrap().days() - time("1600-01-01 00:00").unwrap().days(),
+            366
+        );
+        assert_eq!(
+            time("1518-03-01 00:00").unwrap().timestamp()
+                - time("1518-02-28 23:58").unwrap().timestamp(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_midnight() {
+        // the first nap starts before midnight, the second one after the new year
+        let input = "\
+[1518-02-28 23:40] Guard #7 begins shift
+[1518-02-28 23:58] falls asleep
+[1518-03-01 00:02] wakes up
+[1519-01-01 00:10] falls asleep
+[1518-12-31 23:50] Guard #10 begins shift
+[1519-01-01 00:14] wakes up
+[1518-12-31 00:00] Guard #99 begins shift
+[1518-12-31 00:05] falls asleep
+[1518-12-31 00:08] wakes up
+";
+        let sleep = sleep_schedules(&parse_lines::<Entry>(input).unwrap());
+        let minutes = |guard| -> Vec<usize> { (0..60).filter(|m| sleep[&guard][*m] > 0).collect() };
+        assert_eq!(minutes(7), [0, 1]);
+        assert_eq!(minutes(10), [10, 11, 12, 13]);
+        assert_eq!(minutes(99), [5, 6, 7]);
+        assert_eq!(solve_part1::<Day04>(input), 100);
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(check(SAMPLE).is_ok());
+        for (input, line, message) in [
+            (
+                "[1518-11-01 00:05] falls asleep\n[1518-11-01 00:10] Guard #1 begins shift\n",
+                1,
+                "falls asleep before any shift begins",
+            ),
+            (
+                "[1518-11-01 00:00] Guard #1 begins shift\n[1518-11-01 00:05] falls asleep\n\
+                 [1518-11-01 00:07] wakes up\n[1518-11-01 00:09] wakes up\n",
+                4,
+                "wakes up without falling asleep",
+            ),
+            (
+                "[1518-11-01 00:00] Guard #1 begins shift\n[1518-11-01 00:05] falls asleep\n\
+                 [1518-11-02 00:00] Guard #2 begins shift\n",
+                3,
+                "shift begins while the previous guard is asleep",
+            ),
+            (
+                "[1518-11-01 00:00] Guard #1 begins shift\n[1518-11-02 00:05] falls asleep\n",
+                2,
+                "falls asleep on another night than the guard's shift",
+            ),
+            (
+                "[1518-11-01 00:00] Guard #1 begins shift\n[1518-11-01 00:05] falls asleep\n",
+                2,
+                "falls asleep and never wakes up",
+            ),
+        ] {
+            let error = check(input).unwrap_err();
+            assert_eq!((error.line, error.message.as_str()), (Some(line), message));
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day04>(SAMPLE), 240);
+        assert_eq!(solve_part2::<Day04>(SAMPLE), 4455);
+    }
+
+    #[test]
+    fn test_random_logs() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..50 {
+            let input = gen::guard_log(&mut rng, 40, 6);
+            let (part1, part2) = brute_force(&input);
+            assert!(part1.contains(&solve_part1::<Day04>(&input)), "{input}");
+            assert!(part2.contains(&solve_part2::<Day04>(&input)), "{input}");
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+pub struct Day05;
+
+fn react(a: char, b: char) -> bool {
+    (a.is_ascii_uppercase() && b.is_ascii_lowercase() && a == b.to_ascii_uppercase())
+        || (b.is_ascii_uppercase() && a.is_ascii_lowercase() && b == a.to_ascii_uppercase())
+}
+
+fn reacted_length<T>(polymer: T) -> usize
+where
+    T: Iterator<Item = char>,
+{
+    let mut stack = Vec::new();
+    for c in polymer {
+        let reaction = match stack.last() {
+            Some(x) => react(*x, c),
+            None => false,
+        };
+        if reaction {
+            stack.pop();
+        } else {
+            stack.push(c);
+        }
+    }
+    stack.len()
+}
+
+fn polymer(input: &[String]) -> String {
+    input
+        .iter()
+        .flat_map(|line| line.chars())
+        .filter(|x| (*x).is_alphabetic())
+        .collect()
+}
+
+impl Problem for Day05 {
+    type Input = VecFromLines<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        reacted_length(polymer(input).chars())
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        let input = polymer(input);
+        let char_set: HashSet<char> = input.chars().map(|x| x.to_ascii_lowercase()).collect();
+        char_set
+            .iter()
+            .map(|c| reacted_length(input.chars().filter(|x| !x.eq_ignore_ascii_case(c))))
+            .min()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use aoc_helpers::scaffold::{solve_part1, solve_part2};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const SAMPLE: &str = include_str!("../../inputs/day05-example.txt");
+
+    /// Removes the first reacting pair until there's none.
+    fn brute_force(polymer: impl Iterator<Item = char>) -> usize {
+        let mut units: Vec<char> = polymer.collect();
+        while let Some(idx) = (1..units.len()).find(|idx| react(units[idx - 1], units[*idx])) {
+            units.drain(idx - 1..=idx);
+        }
+        units.len()
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day05>(SAMPLE), 10);
+        assert_eq!(solve_part2::<Day05>(SAMPLE), 4);
+    }
+
+    #[test]
+    fn test_random_polymers() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..50 {
+            let input = gen::polymer(&mut rng, 200, 4);
+            let polymer = input.trim_end();
+            assert_eq!(solve_part1::<Day05>(&input), brute_force(polymer.chars()));
+            assert_eq!(
+                solve_part2::<Day05>(&input),
+                ('a'..='d')
+                    .filter(|unit| polymer.contains(*unit)
+                        || polymer.contains(unit.to_ascii_uppercase()))
+                    .map(|unit| brute_force(
+                        polymer.chars().filter(|c| !c.eq_ignore_ascii_case(&unit))
+                    ))
+                    .min()
+                    .unwrap()
+            );
+        }
+    }
+}
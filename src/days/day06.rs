@@ -0,0 +1,332 @@
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, parse_lines, ParseError};
+
+pub struct Day06;
+
+type Point = (isize, isize);
+
+fn dist(a: Point, b: Point) -> isize {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+fn sum_dists(x: Point, points: &[Point]) -> isize {
+    points.iter().map(|p| dist(x, *p)).sum()
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Coordinates(Point);
+
+impl FromStr for Coordinates {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(", ")
+            .ok_or_else(|| ParseError::at(s, s, "expected `<x>, <y>`"))?;
+        Ok(Self((parse_field(s, x)?, parse_field(s, y)?)))
+    }
+}
+
+fn points(input: &[Coordinates]) -> Vec<Point> {
+    input.iter().map(|Coordinates(p)| *p).collect()
+}
+
+/// Closest point of every cell in the bounding box of all the points, `None` for ties.
+struct Voronoi {
+    min: Point,
+    width: usize,
+    height: usize,
+    owners: Vec<Option<usize>>,
+}
+
+impl Voronoi {
+    /// Multi-source BFS, level by level.
+    ///
+    /// Closest points of a cell are the closest points of its neighbours one step closer to
+    /// them, so a tie spreads further just like a single owner does.
+    fn new(points: &[Point]) -> Self {
+        let min_x = points.iter().map(|p| p.0).min().unwrap();
+        let min_y = points.iter().map(|p| p.1).min().unwrap();
+        let max_x = points.iter().map(|p| p.0).max().unwrap();
+        let max_y = points.iter().map(|p| p.1).max().unwrap();
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+
+        let mut distances = vec![usize::MAX; width * height];
+        let mut owners = vec![None; width * height];
+        let mut frontier = Vec::with_capacity(points.len());
+        for (i, p) in points.iter().enumerate() {
+            let idx = (p.1 - min_y) as usize * width + (p.0 - min_x) as usize;
+            if distances[idx] == 0 {
+                owners[idx] = None;
+            } else {
+                distances[idx] = 0;
+                owners[idx] = Some(i);
+                frontier.push(idx);
+            }
+        }
+
+        let mut distance = 0;
+        while !frontier.is_empty() {
+            distance += 1;
+            let mut next = Vec::new();
+            for idx in frontier {
+                let (x, y) = (idx % width, idx / width);
+                let neighbours = [
+                    (x > 0).then(|| idx - 1),
+                    (x + 1 < width).then(|| idx + 1),
+                    (y > 0).then(|| idx - width),
+                    (y + 1 < height).then(|| idx + width),
+                ];
+                for neighbour in neighbours.into_iter().flatten() {
+                    if distances[neighbour] == usize::MAX {
+                        distances[neighbour] = distance;
+                        owners[neighbour] = owners[idx];
+                        next.push(neighbour);
+                    } else if distances[neighbour] == distance && owners[neighbour] != owners[idx] {
+                        owners[neighbour] = None;
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        Self {
+            min: (min_x, min_y),
+            width,
+            height,
+            owners,
+        }
+    }
+
+    fn owner(&self, p: Point) -> Option<usize> {
+        let (x, y) = ((p.0 - self.min.0) as usize, (p.1 - self.min.1) as usize);
+        self.owners[y * self.width + x]
+    }
+
+    /// Points owning a cell on the border own everything further out in that direction too.
+    fn infinite(&self, points_count: usize) -> Vec<bool> {
+        let (min_x, min_y) = self.min;
+        let (max_x, max_y) = (
+            min_x + self.width as isize - 1,
+            min_y + self.height as isize - 1,
+        );
+        let border = (min_x..=max_x)
+            .flat_map(|x| [(x, min_y), (x, max_y)])
+            .chain((min_y..=max_y).flat_map(|y| [(min_x, y), (max_x, y)]));
+
+        let mut infinite = vec![false; points_count];
+        for p in border {
+            if let Some(i) = self.owner(p) {
+                infinite[i] = true;
+            }
+        }
+        infinite
+    }
+}
+
+fn largest_finite_area(points: &[Point]) -> usize {
+    let voronoi = Voronoi::new(points);
+    let infinite = voronoi.infinite(points.len());
+
+    let mut area = vec![0usize; points.len()];
+    for i in voronoi.owners.iter().flatten() {
+        area[*i] += 1;
+    }
+
+    area.into_iter()
+        .enumerate()
+        .filter(|(i, _)| !infinite[*i])
+        .map(|(_, a)| a)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Number of cells with total distance to all points below `limit`.
+///
+/// The total is smallest at the median and only grows moving away from it along either
+/// axis, so the region is connected and can be flooded from there.
+fn safe_region_size(points: &[Point], limit: isize) -> usize {
+    let median = |mut coords: Vec<isize>| {
+        coords.sort_unstable();
+        coords[coords.len() / 2]
+    };
+    let start = (
+        median(points.iter().map(|p| p.0).collect()),
+        median(points.iter().map(|p| p.1).collect()),
+    );
+    if sum_dists(start, points) >= limit {
+        return 0;
+    }
+
+    let mut region = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some((x, y)) = queue.pop_front() {
+        for neighbour in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+            if !region.contains(&neighbour) && sum_dists(neighbour, points) < limit {
+                region.insert(neighbour);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    region.len()
+}
+
+pub const SAFE_DISTANCE: isize = 10000;
+
+/// Part 2 with `[safe distance]` instead of `SAFE_DISTANCE`.
+pub fn part2_with(input: &str, params: &[usize]) -> String {
+    let distance = match params {
+        [distance] => *distance as isize,
+        _ => panic!("expected [safe distance], got {params:?}"),
+    };
+    let coordinates = parse_lines::<Coordinates>(input).unwrap_or_else(|e| panic!("{e}"));
+    safe_region_size(&points(&coordinates), distance).to_string()
+}
+
+impl Problem for Day06 {
+    type Input = VecFromLines<Coordinates>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        largest_finite_area(&points(input))
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        safe_region_size(&points(input), SAFE_DISTANCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use aoc_helpers::scaffold::solve_part1;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::ops::RangeInclusive;
+
+    const SAMPLE: &str = include_str!("../../inputs/day06-example.txt");
+
+    fn find_owner(x: Point, points: &[Point]) -> Option<usize> {
+        let mut result = None;
+        let mut min_dist = isize::MAX;
+        for (i, p) in points.iter().enumerate() {
+            let d = dist(x, *p);
+            match d.cmp(&min_dist) {
+                std::cmp::Ordering::Less => {
+                    min_dist = d;
+                    result = Some(i);
+                }
+                std::cmp::Ordering::Equal => {
+                    result = None;
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// Bounding box of the points with `margin` added on every side.
+    fn bounds(points: &[Point], margin: isize) -> (RangeInclusive<isize>, RangeInclusive<isize>) {
+        let min_x = points.iter().map(|(a, _)| a).min().unwrap();
+        let min_y = points.iter().map(|(_, a)| a).min().unwrap();
+        let max_x = points.iter().map(|(a, _)| a).max().unwrap();
+        let max_y = points.iter().map(|(_, a)| a).max().unwrap();
+
+        (
+            (min_x - margin)..=(max_x + margin),
+            (min_y - margin)..=(max_y + margin),
+        )
+    }
+
+    /// Checks every cell with all the points in a box so big that only infinite areas reach
+    /// its border.
+    fn brute_force_area(points: &[Point]) -> usize {
+        let max_dist = points
+            .iter()
+            .flat_map(|p1| points.iter().map(|p2| dist(*p1, *p2)))
+            .max()
+            .unwrap();
+        let (x_range, y_range) = bounds(points, max_dist);
+
+        let mut infinite = vec![false; points.len()];
+        let mut area = vec![0usize; points.len()];
+        for x in x_range.clone() {
+            for y in y_range.clone() {
+                if let Some(i) = find_owner((x, y), points) {
+                    area[i] += 1;
+                    if x_range.start() == &x
+                        || x_range.end() == &x
+                        || y_range.start() == &y
+                        || y_range.end() == &y
+                    {
+                        infinite[i] = true;
+                    }
+                }
+            }
+        }
+
+        area.into_iter()
+            .enumerate()
+            .filter(|(i, _)| !infinite[*i])
+            .map(|(_, a)| a)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Further out than `limit / n` the distance to every point alone is too much.
+    fn brute_force_region(points: &[Point], limit: isize) -> usize {
+        let (x_range, y_range) = bounds(points, limit / points.len() as isize + 1);
+        x_range
+            .flat_map(|x| y_range.clone().map(move |y| (x, y)))
+            .filter(|p| sum_dists(*p, points) < limit)
+            .count()
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day06>(SAMPLE), 17);
+        let points = points(&parse_lines(SAMPLE).unwrap());
+        assert_eq!(safe_region_size(&points, 32), 16);
+        assert_eq!(brute_force_area(&points), 17);
+        assert_eq!(brute_force_region(&points, 32), 16);
+    }
+
+    #[test]
+    fn test_ties() {
+        // (0, 0) is as close to the first two points, but only via the tied (1, 0)
+        let voronoi = Voronoi::new(&[(2, 0), (1, 1), (0, 3)]);
+        assert_eq!(voronoi.owner((1, 0)), None);
+        assert_eq!(voronoi.owner((0, 0)), None);
+        assert_eq!(voronoi.owner((0, 1)), Some(1));
+        assert_eq!(voronoi.owner((0, 2)), Some(2));
+        assert_eq!(Voronoi::new(&[(3, 3), (3, 3)]).owner((3, 3)), None);
+    }
+
+    #[test]
+    fn test_random_points() {
+        let mut rng = StdRng::seed_from_u64(6);
+        for _ in 0..30 {
+            let input = gen::coordinates(&mut rng, 12, 40);
+            let points = points(&parse_lines(&input).unwrap());
+            assert_eq!(
+                largest_finite_area(&points),
+                brute_force_area(&points),
+                "{input}"
+            );
+            for limit in [0, 50, 300, 1000] {
+                assert_eq!(
+                    safe_region_size(&points, limit),
+                    brute_force_region(&points, limit),
+                    "{input}"
+                );
+            }
+        }
+    }
+}
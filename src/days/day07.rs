@@ -0,0 +1,857 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, BinaryHeap, HashMap};
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_lines, ParseError};
+
+pub struct Day07;
+
+struct ReverseOrdered<T>(T);
+
+impl<T> fmt::Debug for ReverseOrdered<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> PartialOrd for ReverseOrdered<T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl<T> Ord for ReverseOrdered<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<T> PartialEq for ReverseOrdered<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<T> Eq for ReverseOrdered<T> where T: Eq {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dependency {
+    from: String,
+    to: String,
+}
+
+impl FromStr for Dependency {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex =
+                Regex::new(r"Step ([A-Z]+) must be finished before step ([A-Z]+) can begin.")
+                    .unwrap();
+        }
+
+        let caps = RE.captures(s).ok_or_else(|| {
+            ParseError::at(
+                s,
+                s,
+                "expected `Step <A> must be finished before step <B> can begin.`",
+            )
+        })?;
+        Ok(Self {
+            from: caps[1].to_string(),
+            to: caps[2].to_string(),
+        })
+    }
+}
+
+/// Steps in the order they're done by a single worker.
+fn order(entries: &[Dependency]) -> Result<String, Cycle> {
+    Ok(Graph::new(entries).topological_order()?.concat())
+}
+
+/// Steps which depend on each other in a circle, each on the one before it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cycle(pub Vec<String>);
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for step in &self.0 {
+            write!(f, "{step} -> ")?;
+        }
+        write!(f, "{}", self.0[0])
+    }
+}
+
+impl std::error::Error for Cycle {}
+
+/// Steps and the dependencies between them in both directions.
+struct Graph<'a> {
+    steps: BTreeSet<&'a str>,
+    prerequisites: HashMap<&'a str, BTreeSet<&'a str>>,
+    dependents: HashMap<&'a str, BTreeSet<&'a str>>,
+}
+
+impl<'a> Graph<'a> {
+    fn new(entries: &'a [Dependency]) -> Self {
+        let mut graph = Self {
+            steps: BTreeSet::new(),
+            prerequisites: HashMap::new(),
+            dependents: HashMap::new(),
+        };
+        for Dependency { from, to } in entries {
+            graph.steps.insert(from);
+            graph.steps.insert(to);
+            graph.prerequisites.entry(to).or_default().insert(from);
+            graph.dependents.entry(from).or_default().insert(to);
+        }
+        graph
+    }
+
+    fn prerequisites(&self, step: &str) -> impl Iterator<Item = &'a str> + '_ {
+        self.prerequisites.get(step).into_iter().flatten().copied()
+    }
+
+    fn dependents(&self, step: &str) -> impl Iterator<Item = &'a str> + '_ {
+        self.dependents.get(step).into_iter().flatten().copied()
+    }
+
+    /// Steps in alphabetical order with all their prerequisites listed before them.
+    fn topological_order(&self) -> Result<Vec<&'a str>, Cycle> {
+        let mut waiting_for = self.waiting_for();
+        let mut ready: BinaryHeap<_> = self
+            .steps
+            .iter()
+            .filter(|step| waiting_for[*step] == 0)
+            .map(|step| ReverseOrdered(*step))
+            .collect();
+        let mut order = Vec::with_capacity(self.steps.len());
+        while let Some(ReverseOrdered(step)) = ready.pop() {
+            order.push(step);
+            for dependent in self.dependents(step) {
+                let count = waiting_for.get_mut(dependent).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(ReverseOrdered(dependent));
+                }
+            }
+        }
+        if order.len() == self.steps.len() {
+            Ok(order)
+        } else {
+            Err(self.find_cycle(|step| waiting_for[step] > 0))
+        }
+    }
+
+    /// Number of prerequisites of every step.
+    fn waiting_for(&self) -> HashMap<&'a str, usize> {
+        self.steps
+            .iter()
+            .map(|step| (*step, self.prerequisites(step).count()))
+            .collect()
+    }
+
+    /// Walks back from the first stuck step, every stuck step has a stuck prerequisite so
+    /// the walk has to come back to a step it has already seen.
+    fn find_cycle(&self, stuck: impl Fn(&str) -> bool) -> Cycle {
+        let mut step = *self.steps.iter().find(|step| stuck(step)).unwrap();
+        let mut walk = Vec::new();
+        while !walk.contains(&step) {
+            walk.push(step);
+            step = self
+                .prerequisites(step)
+                .find(|before| stuck(before))
+                .unwrap();
+        }
+        let start = walk.iter().position(|s| *s == step).unwrap();
+        let mut cycle: Vec<String> = walk[start..].iter().rev().map(|s| s.to_string()).collect();
+        // start with the first step alphabetically so the same cycle is always reported the same
+        let first = (0..cycle.len()).min_by_key(|idx| &cycle[*idx]).unwrap();
+        cycle.rotate_left(first);
+        Cycle(cycle)
+    }
+}
+
+/// Checks the steps can be ordered at all, a cycle is reported at the line closing it.
+pub fn check(input: &str) -> Result<(), ParseError> {
+    let entries = parse_lines::<Dependency>(input)?;
+    match Graph::new(&entries).topological_order() {
+        Ok(_) => Ok(()),
+        Err(cycle) => {
+            let (last, first) = (cycle.0.last().unwrap(), &cycle.0[0]);
+            let idx = entries
+                .iter()
+                .position(|d| &d.from == last && &d.to == first)
+                .unwrap();
+            let line = input.lines().nth(idx).unwrap();
+            Err(ParseError::new(format!("dependency cycle {cycle}")).with_line(idx + 1, line))
+        }
+    }
+}
+
+/// Graph in the DOT format, steps of a cycle (if there's one) are highlighted.
+pub fn dot(entries: &[Dependency]) -> String {
+    let graph = Graph::new(entries);
+    let cycle = graph
+        .topological_order()
+        .err()
+        .map_or_else(Vec::new, |cycle| cycle.0);
+    let in_cycle = |from: &str, to: &str| {
+        let from = cycle.iter().position(|step| step == from);
+        from.is_some_and(|idx| cycle[(idx + 1) % cycle.len()] == to)
+    };
+
+    let mut dot = String::from("digraph steps {\n");
+    for step in &graph.steps {
+        let style = if cycle.iter().any(|s| s == step) {
+            " [color=red]"
+        } else {
+            ""
+        };
+        writeln!(dot, "    {step}{style};").unwrap();
+    }
+    for step in &graph.steps {
+        for dependent in graph.dependents(step) {
+            let style = if in_cycle(step, dependent) {
+                " [color=red]"
+            } else {
+                ""
+            };
+            writeln!(dot, "    {step} -> {dependent}{style};").unwrap();
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Every order the steps can be done in by a single worker, the best one by the tie-break
+/// comes first. Nothing for a graph with a cycle.
+pub struct Orderings<'a> {
+    graph: Graph<'a>,
+    rank: HashMap<&'a str, usize>,
+    waiting_for: HashMap<&'a str, usize>,
+    path: Vec<&'a str>,
+    /// Steps to choose from at each position of the path and which one is next to try.
+    stack: Vec<(Vec<&'a str>, usize)>,
+    started: bool,
+}
+
+impl<'a> Orderings<'a> {
+    fn ready(&self) -> Vec<&'a str> {
+        let mut ready: Vec<&str> = self
+            .graph
+            .steps
+            .iter()
+            .copied()
+            .filter(|step| self.waiting_for[step] == 0 && !self.path.contains(step))
+            .collect();
+        ready.sort_by_key(|step| self.rank[step]);
+        ready
+    }
+
+    fn push(&mut self, step: &'a str) {
+        self.path.push(step);
+        for dependent in self.graph.dependents(step) {
+            *self.waiting_for.get_mut(dependent).unwrap() -= 1;
+        }
+    }
+
+    fn pop(&mut self) {
+        let step = self.path.pop().unwrap();
+        for dependent in self.graph.dependents(step) {
+            *self.waiting_for.get_mut(dependent).unwrap() += 1;
+        }
+    }
+}
+
+impl Iterator for Orderings<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if !self.started {
+            self.started = true;
+            self.stack.push((self.ready(), 0));
+        } else if !self.path.is_empty() && self.path.len() == self.graph.steps.len() {
+            // the complete path was returned last time
+            self.pop();
+        }
+        while let Some((candidates, next)) = self.stack.last_mut() {
+            if let Some(step) = candidates.get(*next).copied() {
+                *next += 1;
+                self.push(step);
+                if self.path.len() == self.graph.steps.len() {
+                    return Some(self.path.concat());
+                }
+                self.stack.push((self.ready(), 0));
+            } else {
+                self.stack.pop();
+                if !self.stack.is_empty() {
+                    self.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Time a step takes on its own: `A` is 1, `Z` is 26, longer labels count on like
+/// spreadsheet columns (`AA` is 27).
+pub fn letter_time(step: &str) -> usize {
+    step.bytes()
+        .fold(0, |time, b| time * 26 + (b - b'A' + 1) as usize)
+}
+
+/// Which of the ready steps an idle worker picks up first.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TieBreak {
+    /// The puzzle rule.
+    Alphabetical,
+    /// Longer steps go first, alphabetically among equally long ones.
+    LongestFirst,
+}
+
+/// Runs steps on a number of workers, every step takes `base_time + letter_time(step)`
+/// unless `durations` says otherwise.
+#[derive(Clone, Debug)]
+pub struct Scheduler {
+    pub workers: usize,
+    pub base_time: usize,
+    pub durations: HashMap<String, usize>,
+    pub tie_break: TieBreak,
+}
+
+/// A step as it was scheduled, it runs during `start..end`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Task {
+    pub step: String,
+    pub worker: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Schedule {
+    pub workers: usize,
+    /// In the order the steps were picked up.
+    pub tasks: Vec<Task>,
+}
+
+impl Scheduler {
+    pub fn new(workers: usize, base_time: usize) -> Self {
+        assert!(workers > 0, "there has to be a worker");
+        Self {
+            workers,
+            base_time,
+            durations: HashMap::new(),
+            tie_break: TieBreak::Alphabetical,
+        }
+    }
+
+    pub fn duration(&self, step: &str) -> usize {
+        self.durations
+            .get(step)
+            .copied()
+            .unwrap_or_else(|| self.base_time + letter_time(step))
+    }
+
+    /// Idle workers pick up ready steps right away, time only jumps to the next finish.
+    pub fn run(&self, entries: &[Dependency]) -> Result<Schedule, Cycle> {
+        let graph = Graph::new(entries);
+        graph.topological_order()?;
+        let mut waiting_for = graph.waiting_for();
+        let mut ready: Vec<&str> = graph
+            .steps
+            .iter()
+            .copied()
+            .filter(|step| waiting_for[step] == 0)
+            .collect();
+        let mut busy_until = vec![0; self.workers];
+        let mut in_progress: Vec<(usize, &str)> = Vec::new();
+        let mut tasks = Vec::with_capacity(graph.steps.len());
+        let mut time = 0;
+        loop {
+            while let Some(worker) = (0..self.workers).find(|w| busy_until[*w] <= time) {
+                let step = match self.pick(&ready) {
+                    Some(idx) => ready.swap_remove(idx),
+                    None => break,
+                };
+                let end = time + self.duration(step);
+                busy_until[worker] = end;
+                in_progress.push((end, step));
+                tasks.push(Task {
+                    step: step.to_string(),
+                    worker,
+                    start: time,
+                    end,
+                });
+            }
+            match in_progress.iter().map(|(end, _)| *end).min() {
+                Some(end) => time = end,
+                None => break,
+            }
+            for (_, step) in in_progress.iter().filter(|(end, _)| *end == time) {
+                for dependent in graph.dependents(step) {
+                    let count = waiting_for.get_mut(dependent).unwrap();
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push(dependent);
+                    }
+                }
+            }
+            in_progress.retain(|(end, _)| *end > time);
+        }
+        Ok(Schedule {
+            workers: self.workers,
+            tasks,
+        })
+    }
+
+    /// Sort key of a step by the tie-break, smaller goes first.
+    fn tie_break_key<'s>(&self, step: &'s str) -> (usize, &'s str) {
+        match self.tie_break {
+            TieBreak::Alphabetical => (0, step),
+            TieBreak::LongestFirst => (usize::MAX - self.duration(step), step),
+        }
+    }
+
+    fn pick(&self, ready: &[&str]) -> Option<usize> {
+        (0..ready.len()).min_by_key(|idx| self.tie_break_key(ready[*idx]))
+    }
+
+    /// All the orders a single worker could do the steps in, following the tie-break first.
+    pub fn orderings<'a>(&self, entries: &'a [Dependency]) -> Orderings<'a> {
+        let graph = Graph::new(entries);
+        // with a cycle there's nothing to search, the search would still try every order of
+        // the steps outside the cycle before giving up
+        let started = graph.topological_order().is_err();
+        let mut steps: Vec<&str> = graph.steps.iter().copied().collect();
+        steps.sort_by_key(|step| self.tie_break_key(step));
+        Orderings {
+            rank: steps
+                .iter()
+                .enumerate()
+                .map(|(idx, step)| (*step, idx))
+                .collect(),
+            waiting_for: graph.waiting_for(),
+            graph,
+            path: Vec::new(),
+            stack: Vec::new(),
+            started,
+        }
+    }
+
+    /// The longest chain of dependent steps, no number of workers can finish sooner.
+    pub fn critical_path(&self, entries: &[Dependency]) -> Result<CriticalPath, Cycle> {
+        let graph = Graph::new(entries);
+        // latest finish of a chain ending with the step and the step before it in that chain
+        let mut finish: HashMap<&str, (usize, Option<&str>)> = HashMap::new();
+        for step in graph.topological_order()? {
+            let before = graph
+                .prerequisites(step)
+                .max_by_key(|before| (finish[before].0, ReverseOrdered(*before)));
+            let start = before.map_or(0, |before| finish[before].0);
+            finish.insert(step, (start + self.duration(step), before));
+        }
+
+        let mut last = graph
+            .steps
+            .iter()
+            .copied()
+            .max_by_key(|step| (finish[step].0, ReverseOrdered(*step)));
+        let time = last.map_or(0, |step| finish[step].0);
+        let mut steps = Vec::new();
+        while let Some(step) = last {
+            steps.push(step.to_string());
+            last = finish[step].1;
+        }
+        steps.reverse();
+        Ok(CriticalPath { steps, time })
+    }
+}
+
+impl Schedule {
+    pub fn completion_time(&self) -> usize {
+        self.tasks.iter().map(|task| task.end).max().unwrap_or(0)
+    }
+
+    /// Second by second table, the same as in the puzzle description.
+    pub fn table(&self) -> String {
+        let mut table = String::from("Second");
+        for worker in 1..=self.workers {
+            write!(table, "   Worker {worker}").unwrap();
+        }
+        table.push_str("   Done\n");
+
+        let mut finished: Vec<&Task> = self.tasks.iter().collect();
+        finished.sort_by_key(|task| task.end);
+        for second in 0..=self.completion_time() {
+            let mut row = format!("{second:>4}");
+            for worker in 0..self.workers {
+                let step = self
+                    .tasks
+                    .iter()
+                    .find(|task| task.worker == worker && (task.start..task.end).contains(&second))
+                    .map_or(".", |task| task.step.as_str());
+                pad(&mut row, 12 + 11 * worker);
+                row.push_str(step);
+            }
+            pad(&mut row, 9 + 11 * self.workers);
+            for task in finished.iter().take_while(|task| task.end <= second) {
+                row.push_str(&task.step);
+            }
+            table.push_str(row.trim_end());
+            table.push('\n');
+        }
+        table
+    }
+}
+
+/// Pads `row` with spaces up to `column`, there's always at least one.
+fn pad(row: &mut String, column: usize) {
+    row.push(' ');
+    while row.chars().count() < column {
+        row.push(' ');
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CriticalPath {
+    pub steps: Vec<String>,
+    pub time: usize,
+}
+
+impl fmt::Display for CriticalPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}s)", self.steps.join(" -> "), self.time)
+    }
+}
+
+/// Part 2 with `[workers, base time]` instead of the puzzle's 5 workers and 60 seconds.
+pub fn part2_with(input: &str, params: &[usize]) -> String {
+    let scheduler = match params {
+        [workers, base_time] => Scheduler::new(*workers, *base_time),
+        _ => panic!("expected [workers, base time], got {params:?}"),
+    };
+    let entries = parse_lines::<Dependency>(input).unwrap_or_else(|e| panic!("{e}"));
+    scheduler
+        .run(&entries)
+        .unwrap_or_else(|cycle| panic!("dependency cycle {cycle}"))
+        .completion_time()
+        .to_string()
+}
+
+impl Problem for Day07 {
+    type Input = VecFromLines<Dependency>;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        order(input).unwrap_or_else(|cycle| panic!("dependency cycle {cycle}"))
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        Scheduler::new(5, 60)
+            .run(input)
+            .unwrap_or_else(|cycle| panic!("dependency cycle {cycle}"))
+            .completion_time()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use crate::parse::parse_lines;
+    use aoc_helpers::scaffold::solve_part1;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::BTreeSet;
+
+    const SAMPLE: &str = include_str!("../../inputs/day07-example.txt");
+
+    fn steps(entries: &[Dependency]) -> BTreeSet<&str> {
+        entries
+            .iter()
+            .flat_map(|d| [d.from.as_str(), d.to.as_str()])
+            .collect()
+    }
+
+    fn is_ready(entries: &[Dependency], step: &str, done: &[&str]) -> bool {
+        entries
+            .iter()
+            .all(|d| d.to != step || done.contains(&d.from.as_str()))
+    }
+
+    /// Picks the first ready step from scratch every time.
+    fn brute_force_order(entries: &[Dependency]) -> String {
+        let mut steps = steps(entries);
+        let mut done = Vec::new();
+        while let Some(step) = steps
+            .iter()
+            .copied()
+            .find(|step| is_ready(entries, step, &done))
+        {
+            steps.remove(step);
+            done.push(step);
+        }
+        done.concat()
+    }
+
+    /// Simulates the workers second by second.
+    fn brute_force_time(entries: &[Dependency], workers: usize, base_time: usize) -> usize {
+        let mut waiting = steps(entries);
+        let mut in_progress: Vec<(&str, usize)> = Vec::new();
+        let mut done = Vec::new();
+        let mut time = 0;
+        while !waiting.is_empty() || !in_progress.is_empty() {
+            while in_progress.len() < workers {
+                match waiting
+                    .iter()
+                    .copied()
+                    .find(|step| is_ready(entries, step, &done))
+                {
+                    Some(step) => {
+                        waiting.remove(step);
+                        in_progress.push((step, letter_time(step) + base_time));
+                    }
+                    None => break,
+                }
+            }
+            time += 1;
+            for (step, left) in &mut in_progress {
+                *left -= 1;
+                if *left == 0 {
+                    done.push(*step);
+                }
+            }
+            in_progress.retain(|(_, left)| *left > 0);
+        }
+        time
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day07>(SAMPLE), "CABDFE");
+        let entries = parse_lines::<Dependency>(SAMPLE).unwrap();
+        assert_eq!(
+            Scheduler::new(2, 0)
+                .run(&entries)
+                .unwrap()
+                .completion_time(),
+            15
+        );
+    }
+
+    #[test]
+    fn test_letter_time() {
+        assert_eq!(letter_time("A"), 1);
+        assert_eq!(letter_time("Z"), 26);
+        assert_eq!(letter_time("AA"), 27);
+        assert_eq!(letter_time("BC"), 55);
+    }
+
+    #[test]
+    fn test_table() {
+        let entries = parse_lines::<Dependency>(SAMPLE).unwrap();
+        let expected = "\
+Second   Worker 1   Worker 2   Done
+   0        C          .
+   1        C          .
+   2        C          .
+   3        A          F       C
+   4        B          F       CA
+   5        B          F       CA
+   6        D          F       CAB
+   7        D          F       CAB
+   8        D          F       CAB
+   9        D          .       CABF
+  10        E          .       CABFD
+  11        E          .       CABFD
+  12        E          .       CABFD
+  13        E          .       CABFD
+  14        E          .       CABFD
+  15        .          .       CABFDE
+";
+        assert_eq!(
+            Scheduler::new(2, 0).run(&entries).unwrap().table(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_scheduler_options() {
+        let entries = parse_lines::<Dependency>(SAMPLE).unwrap();
+        let mut scheduler = Scheduler::new(2, 0);
+        scheduler.tie_break = TieBreak::LongestFirst;
+        let schedule = scheduler.run(&entries).unwrap();
+        assert_eq!(schedule.tasks[1].step, "F");
+        assert_eq!(schedule.tasks[2].step, "A");
+        assert_eq!(schedule.completion_time(), 15);
+
+        scheduler.durations.insert("F".to_string(), 1);
+        assert_eq!(scheduler.duration("F"), 1);
+        assert_eq!(scheduler.duration("E"), 5);
+        assert_eq!(scheduler.run(&entries).unwrap().completion_time(), 13);
+        assert_eq!(
+            Scheduler::new(1, 0)
+                .run(&entries)
+                .unwrap()
+                .completion_time(),
+            21
+        );
+    }
+
+    #[test]
+    fn test_critical_path() {
+        let entries = parse_lines::<Dependency>(SAMPLE).unwrap();
+        let path = Scheduler::new(2, 0).critical_path(&entries).unwrap();
+        assert_eq!(path.steps, vec!["C", "F", "E"]);
+        assert_eq!(path.to_string(), "C -> F -> E (14s)");
+        // with a base time the longer chain wins
+        let path = Scheduler::new(5, 60).critical_path(&entries).unwrap();
+        assert_eq!((path.steps.len(), path.time), (4, 253));
+    }
+
+    #[test]
+    fn test_idle_worker_takes_first_ready_step() {
+        // K starts at 5 and F at 10 as soon as a worker is free, I and L then start together
+        // at 16
+        let entries = parse_lines::<Dependency>(
+            "\
+Step E must be finished before step L can begin.
+Step K must be finished before step I can begin.
+Step J must be finished before step F can begin.
+",
+        )
+        .unwrap();
+        let schedule = Scheduler::new(2, 0).run(&entries).unwrap();
+        assert_eq!(schedule.completion_time(), 28);
+    }
+
+    #[test]
+    fn test_random_dependencies() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..50 {
+            let input = gen::dependencies(&mut rng, 12, 10);
+            let entries = parse_lines::<Dependency>(&input).unwrap();
+            assert_eq!(
+                order(&entries).unwrap(),
+                brute_force_order(&entries),
+                "{input}"
+            );
+            for (workers, base_time) in [(1, 0), (2, 0), (5, 60)] {
+                let scheduler = Scheduler::new(workers, base_time);
+                let schedule = scheduler.run(&entries).unwrap();
+                assert_eq!(
+                    schedule.completion_time(),
+                    brute_force_time(&entries, workers, base_time),
+                    "{input}"
+                );
+                assert!(
+                    scheduler.critical_path(&entries).unwrap().time <= schedule.completion_time()
+                );
+            }
+            let orderings: Vec<String> =
+                Scheduler::new(1, 0).orderings(&entries).take(20).collect();
+            assert_eq!(orderings[0], brute_force_order(&entries), "{input}");
+            for ordering in &orderings {
+                assert!(is_valid_order(&entries, ordering), "{input}");
+            }
+        }
+    }
+
+    fn is_valid_order(entries: &[Dependency], order: &str) -> bool {
+        let position = |step: &str| order.find(step).unwrap();
+        entries.iter().all(|d| position(&d.from) < position(&d.to))
+    }
+
+    const CYCLE: &str = "\
+Step A must be finished before step B can begin.
+Step C must be finished before step D can begin.
+Step D must be finished before step B can begin.
+Step B must be finished before step E can begin.
+Step E must be finished before step C can begin.
+";
+
+    #[test]
+    fn test_cycle() {
+        let entries = parse_lines::<Dependency>(CYCLE).unwrap();
+        let cycle = order(&entries).unwrap_err();
+        assert_eq!(cycle.to_string(), "B -> E -> C -> D -> B");
+        assert!(Scheduler::new(2, 0).run(&entries).is_err());
+        assert_eq!(Scheduler::new(1, 0).orderings(&entries).next(), None);
+
+        let error = check(CYCLE).unwrap_err();
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.message, "dependency cycle B -> E -> C -> D -> B");
+        assert!(check(SAMPLE).is_ok());
+    }
+
+    #[test]
+    fn test_orderings_with_cycle() {
+        // the twelve independent steps alone could be ordered in 12! ways
+        let mut input = CYCLE.to_string();
+        for step in 'F'..='Q' {
+            input.push_str(&format!(
+                "Step {step} must be finished before step Z{step} can begin.\n"
+            ));
+        }
+        let entries = parse_lines::<Dependency>(&input).unwrap();
+        assert_eq!(Scheduler::new(1, 0).orderings(&entries).next(), None);
+    }
+
+    #[test]
+    fn test_orderings() {
+        let entries = parse_lines::<Dependency>(SAMPLE).unwrap();
+        let orderings: Vec<String> = Scheduler::new(1, 0).orderings(&entries).collect();
+        assert_eq!(
+            orderings,
+            vec!["CABDFE", "CABFDE", "CADBFE", "CADFBE", "CAFBDE", "CAFDBE", "CFABDE", "CFADBE"]
+        );
+
+        let mut scheduler = Scheduler::new(1, 0);
+        scheduler.tie_break = TieBreak::LongestFirst;
+        let orderings: Vec<String> = scheduler.orderings(&entries).collect();
+        assert_eq!(orderings[0], "CFADBE");
+        assert_eq!(orderings.len(), 8);
+    }
+
+    #[test]
+    fn test_dot() {
+        let entries = parse_lines::<Dependency>(
+            "\
+Step A must be finished before step B can begin.
+Step B must be finished before step C can begin.
+Step C must be finished before step B can begin.
+",
+        )
+        .unwrap();
+        let expected = "\
+digraph steps {
+    A;
+    B [color=red];
+    C [color=red];
+    A -> B;
+    B -> C [color=red];
+    C -> B [color=red];
+}
+";
+        assert_eq!(dot(&entries), expected);
+    }
+}
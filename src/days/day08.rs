@@ -0,0 +1,409 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Write};
+use std::iter;
+use std::str::{FromStr, SplitWhitespace};
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, ParseError};
+
+pub struct Day08;
+
+#[derive(Debug)]
+pub struct Node {
+    children: Vec<Node>,
+    metadata: Vec<usize>,
+}
+
+/// Size of a subtree and how deep it goes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /// Levels of nodes, 1 for a leaf.
+    pub depth: usize,
+    pub size: usize,
+    pub metadata_sum: usize,
+}
+
+/// The puzzle's rule: a leaf is worth its metadata, any other node the values of the
+/// children its metadata points to, counted from 1.
+pub fn license_value(node: &Node, children: &[usize]) -> usize {
+    if children.is_empty() {
+        node.metadata.iter().sum()
+    } else {
+        node.metadata
+            .iter()
+            .filter_map(|m| m.checked_sub(1).and_then(|m| children.get(m)))
+            .sum()
+    }
+}
+
+impl Node {
+    pub fn children(&self) -> &[Node] {
+        &self.children
+    }
+
+    pub fn metadata(&self) -> &[usize] {
+        &self.metadata
+    }
+
+    /// Every node of the tree, parents before their children.
+    pub fn iter_nodes(&self) -> NodeIter<'_> {
+        NodeIter { nodes: vec![self] }
+    }
+
+    /// Every node of the tree, children before their parents.
+    pub fn post_order(&self) -> impl Iterator<Item = &Node> {
+        // `false` until the node's children are on the stack
+        let mut stack = vec![(self, false)];
+        iter::from_fn(move || loop {
+            let (node, expanded) = stack.pop()?;
+            if expanded || node.children.is_empty() {
+                return Some(node);
+            }
+            stack.push((node, true));
+            stack.extend(node.children.iter().rev().map(|child| (child, false)));
+        })
+    }
+
+    /// Every node of the tree level by level, with its level counted from 0.
+    pub fn level_order(&self) -> impl Iterator<Item = (usize, &Node)> {
+        let mut queue = VecDeque::from([(0, self)]);
+        iter::from_fn(move || {
+            let (level, node) = queue.pop_front()?;
+            queue.extend(node.children.iter().map(|child| (level + 1, child)));
+            Some((level, node))
+        })
+    }
+
+    fn iter_metadata(&self) -> impl Iterator<Item = &usize> {
+        self.iter_nodes().flat_map(|n| n.metadata.iter())
+    }
+
+    /// Node reached by following the child indexes in `path`, counted from 0.
+    pub fn get(&self, path: &[usize]) -> Option<&Node> {
+        path.iter()
+            .try_fold(self, |node, idx| node.children.get(*idx))
+    }
+
+    /// Value of every node in pre-order, `rule` gets a node and the values of its children.
+    ///
+    /// Computed bottom-up so deep trees don't recurse.
+    pub fn values_with<T: Clone>(&self, mut rule: impl FnMut(&Node, &[T]) -> T) -> Vec<T> {
+        let nodes: Vec<&Node> = self.iter_nodes().collect();
+        // pre-order indexes of every node's children
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        let mut stack: Vec<(&Node, Option<usize>)> = vec![(self, None)];
+        let mut idx = 0;
+        while let Some((node, parent)) = stack.pop() {
+            if let Some(parent) = parent {
+                children[parent].push(idx);
+            }
+            stack.extend(node.children.iter().rev().map(|child| (child, Some(idx))));
+            idx += 1;
+        }
+        // children come after their parent, so going backwards they're always done first
+        let mut values: Vec<Option<T>> = vec![None; nodes.len()];
+        let mut child_values = Vec::new();
+        for idx in (0..nodes.len()).rev() {
+            child_values.clear();
+            child_values.extend(
+                children[idx]
+                    .iter()
+                    .map(|child| values[*child].clone().unwrap()),
+            );
+            values[idx] = Some(rule(nodes[idx], &child_values));
+        }
+        values.into_iter().map(Option::unwrap).collect()
+    }
+
+    pub fn value_with<T: Clone>(&self, rule: impl FnMut(&Node, &[T]) -> T) -> T {
+        self.values_with(rule).swap_remove(0)
+    }
+
+    pub fn value(&self) -> usize {
+        self.value_with(license_value)
+    }
+
+    /// Stats of every subtree in pre-order.
+    pub fn subtree_stats(&self) -> Vec<Stats> {
+        self.values_with(|node, children: &[Stats]| Stats {
+            depth: children.iter().map(|c| c.depth).max().unwrap_or(0) + 1,
+            size: children.iter().map(|c| c.size).sum::<usize>() + 1,
+            metadata_sum: children.iter().map(|c| c.metadata_sum).sum::<usize>()
+                + node.metadata.iter().sum::<usize>(),
+        })
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.subtree_stats().swap_remove(0)
+    }
+
+    /// The tree a node per line, each with its value and metadata.
+    pub fn pretty(&self) -> String {
+        let values = self.values_with(license_value);
+        let mut result = String::new();
+        // prefix of the node's line and of its children's lines
+        let mut stack = vec![(self, String::new(), String::new())];
+        for value in values {
+            let (node, prefix, child_prefix) = stack.pop().unwrap();
+            writeln!(
+                result,
+                "{prefix}value {value}, metadata {:?}",
+                node.metadata
+            )
+            .unwrap();
+            let last = node.children.len().saturating_sub(1);
+            for (idx, child) in node.children.iter().enumerate().rev() {
+                let (branch, indent) = if idx == last {
+                    ("└─ ", "   ")
+                } else {
+                    ("├─ ", "│  ")
+                };
+                stack.push((
+                    child,
+                    format!("{child_prefix}{branch}"),
+                    format!("{child_prefix}{indent}"),
+                ));
+            }
+        }
+        result
+    }
+}
+
+impl Drop for Node {
+    // the default drop recurses once per level
+    fn drop(&mut self) {
+        let mut nodes = std::mem::take(&mut self.children);
+        while let Some(mut node) = nodes.pop() {
+            nodes.append(&mut node.children);
+        }
+    }
+}
+
+/// Writes the tree back in the input format.
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+        let mut number = |f: &mut fmt::Formatter, number: usize| {
+            let separator = if first { "" } else { " " };
+            first = false;
+            write!(f, "{separator}{number}")
+        };
+        // `false` once the node's children are written and its metadata is next
+        let mut stack = vec![(self, true)];
+        while let Some((node, enter)) = stack.pop() {
+            if enter {
+                number(f, node.children.len())?;
+                number(f, node.metadata.len())?;
+                stack.push((node, false));
+                stack.extend(node.children.iter().rev().map(|child| (child, true)));
+            } else {
+                for m in &node.metadata {
+                    number(f, *m)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct NodeIter<'a> {
+    nodes: Vec<&'a Node>,
+}
+
+impl<'a> Iterator for NodeIter<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node_option = self.nodes.pop();
+        if let Some(node) = node_option {
+            self.nodes.extend(node.children.iter().rev())
+        }
+        node_option
+    }
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_whitespace();
+        let next = |fields: &mut SplitWhitespace| match fields.next() {
+            Some(field) => parse_field(s, field),
+            None => Err(ParseError::at(
+                s,
+                &s[s.len()..],
+                "unexpected end of the tree",
+            )),
+        };
+
+        // nodes still being read, with the number of children left to read
+        let mut stack: Vec<(usize, usize, Vec<Node>)> = Vec::new();
+        loop {
+            let child_count = next(&mut fields)?;
+            let metadata_count = next(&mut fields)?;
+            stack.push((child_count, metadata_count, Vec::new()));
+            while stack.last().is_some_and(|(left, _, _)| *left == 0) {
+                let (_, metadata_count, children) = stack.pop().unwrap();
+                let metadata = (0..metadata_count)
+                    .map(|_| next(&mut fields))
+                    .collect::<Result<_, _>>()?;
+                let node = Node { children, metadata };
+                match stack.last_mut() {
+                    Some((left, _, siblings)) => {
+                        *left -= 1;
+                        siblings.push(node);
+                    }
+                    None => {
+                        return match fields.next() {
+                            Some(field) => {
+                                Err(ParseError::at(s, field, "trailing numbers after the tree"))
+                            }
+                            None => Ok(node),
+                        };
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Problem for Day08 {
+    type Input = VecFromLines<Node>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        input.iter().flat_map(|tree| tree.iter_metadata()).sum()
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        input.iter().map(|tree| tree.value()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_helpers::scaffold::{solve_part1, solve_part2};
+
+    const SAMPLE: &str = include_str!("../../inputs/day08-example.txt");
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2 7"
+                .parse::<Node>()
+                .unwrap_err()
+                .column,
+            37
+        );
+        assert_eq!(
+            "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1"
+                .parse::<Node>()
+                .unwrap_err()
+                .column,
+            34
+        );
+        assert_eq!("2 3 0 3 10 1x".parse::<Node>().unwrap_err().column, 12);
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day08>(SAMPLE), 138);
+        assert_eq!(solve_part2::<Day08>(SAMPLE), 66);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let tree: Node = SAMPLE.trim().parse().unwrap();
+        assert_eq!(tree.to_string(), SAMPLE.trim());
+        assert_eq!(tree.to_string().parse::<Node>().unwrap().value(), 66);
+        assert_eq!("0 0".parse::<Node>().unwrap().to_string(), "0 0");
+        assert_eq!(
+            tree.pretty(),
+            "\
+value 66, metadata [1, 1, 2]
+├─ value 33, metadata [10, 11, 12]
+└─ value 0, metadata [2]
+   └─ value 99, metadata [99]
+"
+        );
+    }
+
+    #[test]
+    fn test_queries() {
+        let tree: Node = SAMPLE.trim().parse().unwrap();
+        let first = |node: &Node| node.metadata()[0];
+        assert_eq!(
+            tree.iter_nodes().map(first).collect::<Vec<_>>(),
+            [1, 10, 2, 99]
+        );
+        assert_eq!(
+            tree.post_order().map(first).collect::<Vec<_>>(),
+            [10, 99, 2, 1]
+        );
+        assert_eq!(
+            tree.level_order()
+                .map(|(level, node)| (level, first(node)))
+                .collect::<Vec<_>>(),
+            [(0, 1), (1, 10), (1, 2), (2, 99)]
+        );
+
+        assert_eq!(tree.get(&[]).map(first), Some(1));
+        assert_eq!(tree.get(&[1, 0]).map(first), Some(99));
+        assert!(tree.get(&[2]).is_none() && tree.get(&[0, 0]).is_none());
+
+        assert_eq!(
+            tree.stats(),
+            Stats {
+                depth: 3,
+                size: 4,
+                metadata_sum: 138
+            }
+        );
+        let stats = tree.subtree_stats();
+        assert_eq!(
+            stats
+                .iter()
+                .map(|s| (s.depth, s.size, s.metadata_sum))
+                .collect::<Vec<_>>(),
+            [(3, 4, 138), (1, 1, 33), (2, 2, 101), (1, 1, 99)]
+        );
+    }
+
+    #[test]
+    fn test_value_rules() {
+        let tree: Node = SAMPLE.trim().parse().unwrap();
+        assert_eq!(tree.values_with(license_value), [66, 33, 0, 99]);
+        let leaves = tree.value_with(|_, children: &[usize]| children.iter().sum::<usize>().max(1));
+        assert_eq!(leaves, 2);
+        // biggest metadata entry anywhere in the subtree
+        let biggest = |node: &Node, children: &[usize]| {
+            children
+                .iter()
+                .chain(node.metadata())
+                .copied()
+                .max()
+                .unwrap_or(0)
+        };
+        assert_eq!(tree.values_with(biggest), [99, 12, 99, 99]);
+    }
+
+    #[test]
+    fn test_deep_tree() {
+        // a chain of nodes, each one's value is its only child's
+        let depth = 100_000;
+        let input = format!("{}0 1 5{}", "1 1 ".repeat(depth), " 1".repeat(depth));
+        let tree: Node = input.parse().unwrap();
+        assert_eq!(tree.iter_metadata().sum::<usize>(), depth + 5);
+        assert_eq!(tree.value(), 5);
+        assert_eq!(tree.to_string(), input);
+        assert_eq!(tree.stats().depth, depth + 1);
+        assert_eq!(tree.post_order().count(), depth + 1);
+        assert_eq!(
+            tree.get(&vec![0; depth]).map(Node::metadata),
+            Some(&[5][..])
+        );
+    }
+}
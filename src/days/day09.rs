@@ -0,0 +1,326 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::circular_list::CircularList;
+use crate::parse::{parse_field, ParseError};
+
+pub struct Day09;
+
+/// Rules of the marble game, the defaults are the puzzle's.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MarbleGame {
+    players: usize,
+    last_marble: usize,
+    special: usize,
+    removal_offset: usize,
+    insert_offset: usize,
+}
+
+impl MarbleGame {
+    pub fn new(players: usize, last_marble: usize) -> Self {
+        assert!(players > 0, "nobody to play");
+        Self {
+            players,
+            last_marble,
+            special: 23,
+            removal_offset: 7,
+            insert_offset: 1,
+        }
+    }
+
+    /// Multiples of `special` are kept by the player instead of being placed.
+    pub fn special(self, special: usize) -> Self {
+        // a special marble on every turn would empty the circle
+        assert!(special > 1, "every marble is special");
+        Self { special, ..self }
+    }
+
+    /// How many marbles counter-clockwise of the current one is the removed marble.
+    pub fn removal_offset(self, removal_offset: usize) -> Self {
+        Self {
+            removal_offset,
+            ..self
+        }
+    }
+
+    /// How many marbles clockwise of the current one is a new marble placed after.
+    pub fn insert_offset(self, insert_offset: usize) -> Self {
+        Self {
+            insert_offset,
+            ..self
+        }
+    }
+
+    /// What happens on each turn, in order.
+    pub fn events(&self) -> Events {
+        Events {
+            game: *self,
+            circle: CircularList::new(0, self.last_marble + 1),
+            marble: 0,
+        }
+    }
+
+    pub fn play(&self) -> Scoreboard {
+        let mut scores = vec![0; self.players];
+        for event in self.events() {
+            if let Event::Scored { player, points, .. } = event {
+                scores[player - 1] += points;
+            }
+        }
+        Scoreboard { scores }
+    }
+
+    /// Same game on a `VecDeque` rotated so the current marble is always at the back.
+    pub fn play_with_deque(&self) -> Scoreboard {
+        let mut circle = VecDeque::with_capacity(self.last_marble + 1);
+        circle.push_back(0);
+        let mut scores = vec![0; self.players];
+        for marble in 1..=self.last_marble {
+            let len = circle.len();
+            if marble % self.special == 0 {
+                circle.rotate_right(self.removal_offset % len);
+                let removed = circle.pop_back().unwrap();
+                // the marble clockwise of the removed one is at the front
+                circle.rotate_left(1);
+                scores[(marble - 1) % self.players] += marble + removed;
+            } else {
+                circle.rotate_left(self.insert_offset % len);
+                circle.push_back(marble);
+            }
+        }
+        Scoreboard { scores }
+    }
+}
+
+/// A turn of the game, players are numbered from 1.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Event {
+    Placed {
+        player: usize,
+        marble: usize,
+    },
+    /// The player kept `marble` and took `removed` from the circle.
+    Scored {
+        player: usize,
+        marble: usize,
+        removed: usize,
+        points: usize,
+    },
+}
+
+pub struct Events {
+    game: MarbleGame,
+    circle: CircularList,
+    marble: usize,
+}
+
+impl Iterator for Events {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        if self.marble == self.game.last_marble {
+            return None;
+        }
+        self.marble += 1;
+        let (marble, player) = (self.marble, (self.marble - 1) % self.game.players + 1);
+        if marble % self.game.special == 0 {
+            self.circle.rotate(-(self.game.removal_offset as isize));
+            let removed = self.circle.remove();
+            Some(Event::Scored {
+                player,
+                marble,
+                removed,
+                points: marble + removed,
+            })
+        } else {
+            self.circle.rotate(self.game.insert_offset as isize);
+            self.circle.insert_after(marble);
+            Some(Event::Placed { player, marble })
+        }
+    }
+}
+
+/// Final score of every player, the first one is player 1's.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Scoreboard {
+    pub scores: Vec<usize>,
+}
+
+impl Scoreboard {
+    pub fn high_score(&self) -> usize {
+        self.scores.iter().copied().max().unwrap()
+    }
+
+    /// Player with the high score, the first one of them if there's a tie.
+    pub fn winner(&self) -> usize {
+        let high_score = self.high_score();
+        self.scores
+            .iter()
+            .position(|score| *score == high_score)
+            .unwrap()
+            + 1
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Game {
+    players: usize,
+    last_marble: usize,
+}
+
+impl Game {
+    /// Rules of the game with the last marble worth `times` as many points.
+    pub fn rules(&self, times: usize) -> MarbleGame {
+        MarbleGame::new(self.players, self.last_marble * times)
+    }
+
+    fn high_score(&self) -> usize {
+        self.rules(1).play().high_score()
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex =
+                Regex::new(r"(\d+) players; last marble is worth (\d+) points").unwrap();
+        }
+
+        let caps = RE.captures(s).ok_or_else(|| {
+            ParseError::at(
+                s,
+                s,
+                "expected `<n> players; last marble is worth <n> points`",
+            )
+        })?;
+        Ok(Self {
+            players: parse_field(s, &caps[1])?,
+            last_marble: parse_field(s, &caps[2])?,
+        })
+    }
+}
+
+/// Winning scores for every game listed in the input.
+pub fn high_scores(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse::<Game>().unwrap().high_score())
+        .collect()
+}
+
+impl Problem for Day09 {
+    type Input = VecFromLines<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        input[0].high_score()
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        input[0].rules(100).play().high_score()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const SAMPLE: &str = include_str!("../../inputs/day09-example.txt");
+
+    /// Plays the game on a plain `Vec`, returns all the scores.
+    fn brute_force(game: &MarbleGame) -> Vec<usize> {
+        let mut circle = vec![0];
+        let mut current = 0;
+        let mut scores = vec![0; game.players];
+        for marble in 1..=game.last_marble {
+            let len = circle.len();
+            if marble % game.special == 0 {
+                current = (current + len - game.removal_offset % len) % len;
+                scores[(marble - 1) % game.players] += marble + circle.remove(current);
+                if current == circle.len() {
+                    current = 0;
+                }
+            } else {
+                current = (current + game.insert_offset) % len + 1;
+                circle.insert(current, marble);
+            }
+        }
+        scores
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(
+            high_scores(SAMPLE),
+            vec![32, 8317, 146373, 2764, 54718, 37305]
+        );
+    }
+
+    #[test]
+    fn test_random_games() {
+        let mut rng = StdRng::seed_from_u64(9);
+        for _ in 0..100 {
+            let game: Game = gen::marble_game(&mut rng, 2000).trim_end().parse().unwrap();
+            let expected = brute_force(&MarbleGame::new(game.players, game.last_marble));
+            assert_eq!(
+                game.high_score(),
+                *expected.iter().max().unwrap(),
+                "{game:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_events() {
+        let game = MarbleGame::new(9, 25);
+        let events: Vec<Event> = game.events().collect();
+        assert_eq!(events.len(), 25);
+        assert_eq!(
+            events[0],
+            Event::Placed {
+                player: 1,
+                marble: 1
+            }
+        );
+        assert_eq!(
+            events[22],
+            Event::Scored {
+                player: 5,
+                marble: 23,
+                removed: 9,
+                points: 32
+            }
+        );
+        let scoreboard = game.play();
+        assert_eq!(scoreboard.scores, vec![0, 0, 0, 0, 32, 0, 0, 0, 0]);
+        assert_eq!((scoreboard.winner(), scoreboard.high_score()), (5, 32));
+
+        let scoreboard = MarbleGame::new(10, 1618).play();
+        assert_eq!(scoreboard.high_score(), 8317);
+    }
+
+    #[test]
+    fn test_variants() {
+        let mut rng = StdRng::seed_from_u64(46);
+        for _ in 0..100 {
+            let game = MarbleGame::new(rng.gen_range(1..20), rng.gen_range(1..500))
+                .special(rng.gen_range(2..30))
+                .removal_offset(rng.gen_range(0..12))
+                .insert_offset(rng.gen_range(0..4));
+            let scores = brute_force(&game);
+            assert_eq!(game.play().scores, scores, "{game:?}");
+            assert_eq!(game.play_with_deque().scores, scores, "{game:?}");
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::ocr;
+use crate::parse::{parse_field, ParseError};
+
+pub struct Day10;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Light {
+    x: isize,
+    y: isize,
+    dx: isize,
+    dy: isize,
+}
+
+impl FromStr for Light {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                r"position=< *(?P<x>-?\d+), *(?P<y>-?\d+)> velocity=< *(?P<dx>-?\d+), *(?P<dy>-?\d+)>",
+            )
+            .unwrap();
+        }
+
+        let caps = RE
+            .captures(s)
+            .ok_or_else(|| ParseError::at(s, s, "expected `position=<x, y> velocity=<dx, dy>`"))?;
+        Ok(Self {
+            x: parse_field(s, &caps["x"])?,
+            y: parse_field(s, &caps["y"])?,
+            dx: parse_field(s, &caps["dx"])?,
+            dy: parse_field(s, &caps["dy"])?,
+        })
+    }
+}
+
+fn position(light: &Light, t: isize) -> (isize, isize) {
+    (light.x + light.dx * t, light.y + light.dy * t)
+}
+
+/// Width plus height of the bounding box of the lights at time `t`.
+///
+/// Every coordinate moves linearly so the extent of the box is a convex function of `t`,
+/// its minimum is where the lights come closest together to form the message.
+fn spread(lights: &[Light], t: isize) -> isize {
+    let (mut min_x, mut max_x) = (isize::MAX, isize::MIN);
+    let (mut min_y, mut max_y) = (isize::MAX, isize::MIN);
+    for light in lights {
+        let (x, y) = position(light, t);
+        (min_x, max_x) = (min_x.min(x), max_x.max(x));
+        (min_y, max_y) = (min_y.min(y), max_y.max(y));
+    }
+    (max_x - min_x) + (max_y - min_y)
+}
+
+/// First second with the smallest bounding box.
+///
+/// Doubles the range while the box still shrinks, then binary searches for where it stops
+/// shrinking (a convex function can only be flat at its minimum).
+fn convergence_time(lights: &[Light]) -> usize {
+    let shrinks = |t| spread(lights, t + 1) < spread(lights, t);
+    let mut high = 1;
+    while shrinks(high) {
+        high *= 2;
+    }
+    let mut low = 0;
+    while low < high {
+        let mid = (low + high) / 2;
+        if shrinks(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low as usize
+}
+
+/// The message read from the lights (or drawn if it's not in the known font) and when it
+/// appears.
+fn find_message(lights: &[Light]) -> (String, usize) {
+    let t = convergence_time(lights);
+    let frame = ocr::grid(lights.iter().map(|light| position(light, t as isize)));
+    (
+        ocr::read_grid(&frame).unwrap_or_else(|_| ocr::render(&frame)),
+        t,
+    )
+}
+
+impl Problem for Day10 {
+    type Input = VecFromLines<Light>;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        find_message(input).0
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        find_message(input).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ocr::{Font, OcrError};
+    use crate::parse::parse_lines;
+    use aoc_helpers::scaffold::{solve_part1, solve_part2};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const SAMPLE: &str = include_str!("../../inputs/day10-example.txt");
+
+    /// Lights spelling `text` at time `t`, flying in from random directions.
+    fn lights_for(rng: &mut StdRng, text: &str, t: isize) -> Vec<Light> {
+        let mut lights = Vec::new();
+        for (y, row) in Font::Large.draw(text).unwrap().iter().enumerate() {
+            for x in (0..row.len()).filter(|x| row[*x]) {
+                let (dx, dy) = (rng.gen_range(-5..=5), rng.gen_range(-5..=5));
+                lights.push(Light {
+                    x: x as isize - dx * t,
+                    y: y as isize - dy * t,
+                    dx,
+                    dy,
+                });
+            }
+        }
+        lights
+    }
+
+    #[test]
+    fn test_sample() {
+        let expected = "\
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###
+";
+        // the sample font is only 8 pixels tall, so it's just drawn
+        assert_eq!(solve_part1::<Day10>(SAMPLE), expected);
+        assert_eq!(solve_part2::<Day10>(SAMPLE), 3);
+        let lights: Vec<Light> = parse_lines(SAMPLE).unwrap();
+        let points = lights.iter().map(|light| position(light, 3));
+        assert_eq!(ocr::read_points(points), Err(OcrError::Height(8)));
+    }
+
+    #[test]
+    fn test_read() {
+        let mut rng = StdRng::seed_from_u64(10);
+        for (text, t) in [("GFANEHKJ", 10086), ("ZEBRA", 7), ("PLEX", 0), ("CL", 1)] {
+            let lights = lights_for(&mut rng, text, t);
+            assert_eq!(find_message(&lights), (text.to_string(), t as usize));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::summed_area::{Square, SummedAreaTable};
+
+pub struct Day11;
+
+fn power_level(x: usize, y: usize, serial_number: usize) -> isize {
+    let rack_id = x + 10;
+    let hundreds = (rack_id * y + serial_number) * rack_id / 100 % 10;
+    hundreds as isize - 5
+}
+
+/// Top left cell of a square of fuel cells, its size and total power.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PowerSquare {
+    pub x: usize,
+    pub y: usize,
+    pub size: usize,
+    pub power: isize,
+}
+
+impl From<Square<isize>> for PowerSquare {
+    fn from(square: Square<isize>) -> Self {
+        Self {
+            x: square.x,
+            y: square.y,
+            size: square.size,
+            power: square.sum,
+        }
+    }
+}
+
+/// Power levels of all the fuel cells, numbered from 1.
+pub struct FuelGrid {
+    table: SummedAreaTable<isize>,
+}
+
+impl FuelGrid {
+    pub fn new(serial_number: usize, width: usize, height: usize) -> Self {
+        Self {
+            table: SummedAreaTable::new(width, height, |x, y| power_level(x, y, serial_number)),
+        }
+    }
+
+    /// The square of the given size with the most power, `None` if the grid is smaller.
+    pub fn best_square(&self, size: usize) -> Option<PowerSquare> {
+        self.table.best_square(size).map(PowerSquare::from)
+    }
+
+    pub fn best_any_size(&self) -> Option<PowerSquare> {
+        self.table.best_any_square().map(PowerSquare::from)
+    }
+}
+
+pub const GRID_SIZE: usize = 300;
+
+fn grid(input: &[usize]) -> FuelGrid {
+    FuelGrid::new(input[0], GRID_SIZE, GRID_SIZE)
+}
+
+impl Problem for Day11 {
+    type Input = VecFromLines<usize>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        let square = grid(input).best_square(3).unwrap();
+        format!("{},{}", square.x, square.y)
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        let square = grid(input).best_any_size().unwrap();
+        format!("{},{},{}", square.x, square.y, square.size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_power_level() {
+        assert_eq!(power_level(3, 5, 8), 4);
+        assert_eq!(power_level(122, 79, 57), -5);
+        assert_eq!(power_level(217, 196, 39), 0);
+        assert_eq!(power_level(101, 153, 71), 4);
+    }
+
+    #[test]
+    fn test_best_squares() {
+        let square = |x, y, size, power| Some(PowerSquare { x, y, size, power });
+        let grid = FuelGrid::new(18, 300, 300);
+        assert_eq!(grid.best_square(3), square(33, 45, 3, 29));
+        assert_eq!(grid.best_any_size(), square(90, 269, 16, 113));
+        let grid = FuelGrid::new(42, 300, 300);
+        assert_eq!(grid.best_square(3), square(21, 61, 3, 30));
+        assert_eq!(grid.best_any_size(), square(232, 251, 12, 119));
+
+        let small = FuelGrid::new(18, 2, 5);
+        assert_eq!(small.best_square(3), None);
+        assert!(small.best_any_size().unwrap().size <= 2);
+    }
+}
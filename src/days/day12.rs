@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::automaton::{self, Row, Rule};
+use crate::parse::{parse_lines, ParseError};
+
+pub struct Day12;
+
+type Pattern = [bool; 5];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Line {
+    Initial(Vec<bool>),
+    Rule(Pattern, bool),
+    Blank,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Line::Blank);
+        }
+        if let Some(state) = s.strip_prefix("initial state: ") {
+            return Ok(Line::Initial(pots(s, state)?));
+        }
+        let (pattern, result) = s
+            .split_once(" => ")
+            .ok_or_else(|| ParseError::at(s, s, "expected `<pattern> => <pot>`"))?;
+        match (pots(s, pattern)?.try_into(), &pots(s, result)?[..]) {
+            (Ok(pattern), &[result]) => Ok(Line::Rule(pattern, result)),
+            (Ok(_), _) => Err(ParseError::at(s, result, "expected a single pot")),
+            (Err(_), _) => Err(ParseError::at(s, pattern, "expected 5 pots")),
+        }
+    }
+}
+
+fn pots(line: &str, text: &str) -> Result<Vec<bool>, ParseError> {
+    text.char_indices()
+        .map(|(idx, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::at(line, &text[idx..], "expected `#` or `.`")),
+        })
+        .collect()
+}
+
+/// Row of pots with plants and the rule spreading them.
+///
+/// Patterns missing from the notes don't grow a plant, so notes listing only the `=> #`
+/// rules work the same as ones listing all 32 patterns.
+#[derive(Clone, Debug)]
+pub struct Pots {
+    row: Row,
+    rule: Rule,
+}
+
+impl Pots {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines = parse_lines::<Line>(input)?;
+        Self::from_lines(&lines).map_err(|(idx, message)| match idx {
+            Some(idx) => {
+                ParseError::new(message).with_line(idx + 1, input.lines().nth(idx).unwrap())
+            }
+            None => ParseError::new(message),
+        })
+    }
+
+    /// Error comes with the index of the line causing it, if there's one.
+    fn from_lines(lines: &[Line]) -> Result<Self, (Option<usize>, &'static str)> {
+        let mut row = None;
+        let mut rule = Rule::new(2);
+        let mut seen = HashMap::new();
+        for (idx, line) in lines.iter().enumerate() {
+            match line {
+                Line::Initial(_) if row.is_some() => {
+                    return Err((Some(idx), "initial state given twice"));
+                }
+                Line::Initial(state) => row = Some(Row::new(0, state.iter().copied())),
+                Line::Rule(pattern, true) if pattern.iter().all(|pot| !pot) => {
+                    return Err((Some(idx), "plants can't grow with no plants around"));
+                }
+                Line::Rule(pattern, result) => {
+                    if seen
+                        .insert(pattern, *result)
+                        .is_some_and(|prev| prev != *result)
+                    {
+                        return Err((Some(idx), "conflicting rules for the same pattern"));
+                    }
+                    rule.set(pattern, *result);
+                }
+                Line::Blank => {}
+            }
+        }
+        match row {
+            Some(row) => Ok(Self { row, rule }),
+            None => Err((None, "missing `initial state: ` line")),
+        }
+    }
+
+    pub fn step(&mut self) {
+        self.row = self.row.step(&self.rule);
+    }
+
+    /// Sum of the numbers of the pots with a plant.
+    pub fn score(&self) -> isize {
+        self.row.sum()
+    }
+
+    /// Score `generations` from now, long runs rely on the plants settling into a cycle.
+    pub fn score_after(&self, generations: u64) -> isize {
+        automaton::after(&self.rule, &self.row, generations).sum()
+    }
+}
+
+impl fmt::Display for Pots {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.row.offset(), self.row)
+    }
+}
+
+pub fn check(input: &str) -> Result<(), ParseError> {
+    Pots::parse(input).map(|_| ())
+}
+
+fn pots_from(input: &[Line]) -> Pots {
+    Pots::from_lines(input).unwrap_or_else(|(_, message)| panic!("{message}"))
+}
+
+impl Problem for Day12 {
+    type Input = VecFromLines<Line>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        pots_from(input).score_after(20)
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        pots_from(input).score_after(50_000_000_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_helpers::scaffold::solve_part1;
+
+    const SAMPLE: &str = include_str!("../../inputs/day12-example.txt");
+
+    /// The sample with the `=> .` rules listed too.
+    fn all_rules(input: &str) -> String {
+        let mut full = input.to_string();
+        for idx in 0..32 {
+            let pattern: String = (0..5)
+                .rev()
+                .map(|bit| if idx & (1 << bit) != 0 { '#' } else { '.' })
+                .collect();
+            if !input.contains(&format!("{pattern} => #")) {
+                full.push_str(&format!("{pattern} => .\n"));
+            }
+        }
+        full
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day12>(SAMPLE), 325);
+
+        let mut pots = Pots::parse(SAMPLE).unwrap();
+        pots.step();
+        assert_eq!(pots.to_string(), "0: #...#....#.....#..#..#..#");
+        for _ in 1..20 {
+            pots.step();
+        }
+        assert_eq!(
+            pots.to_string(),
+            "-2: #....##....#####...#######....#.#..##"
+        );
+        assert_eq!(pots.score(), 325);
+        assert_eq!(pots.score_after(0), 325);
+
+        let full = all_rules(SAMPLE);
+        assert_eq!(full.lines().count(), 34);
+        let full = Pots::parse(&full).unwrap();
+        assert_eq!(full.score_after(20), 325);
+        assert_eq!(full.score_after(50_000_000_000), 999999999374);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Pots::parse("...## => #\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (None, "missing `initial state: ` line")
+        );
+
+        let error = Pots::parse("initial state: #.#\n\n..#.. => #\n..#.. => .\n").unwrap_err();
+        assert_eq!(error.line, Some(4));
+        assert_eq!(error.message, "conflicting rules for the same pattern");
+
+        let error = Pots::parse("initial state: #\n..... => #\n").unwrap_err();
+        assert_eq!(error.line, Some(2));
+
+        let error = Pots::parse("initial state: #\n..#. => #\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), 1));
+        assert_eq!(error.message, "expected 5 pots");
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
-use std::io::{self, Read};
-use std::iter::repeat;
+use std::fmt;
+
+use aoc_helpers::scaffold::{Problem, RowsOfChars};
+
+pub struct Day13;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 struct Coords(usize, usize);
@@ -210,19 +213,14 @@ impl MapField {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 enum Action {
+    #[default]
     Left,
     Straight,
     Right,
 }
 
-impl Default for Action {
-    fn default() -> Self {
-        Action::Left
-    }
-}
-
 impl Action {
     fn next(self) -> Self {
         use self::Action::*;
@@ -293,47 +291,7 @@ impl Map {
         Map { fields, carts }
     }
 
-    fn print_out(&self) {
-        let min_row = self.fields.keys().map(|Coords(row, _)| *row).min().unwrap();
-        let max_row = self.fields.keys().map(|Coords(row, _)| *row).max().unwrap();
-        let min_column = self
-            .fields
-            .keys()
-            .map(|Coords(_, column)| *column)
-            .min()
-            .unwrap();
-        let max_column = self
-            .fields
-            .keys()
-            .map(|Coords(_, column)| *column)
-            .max()
-            .unwrap();
-
-        let carts: HashMap<Coords, &Cart> = self
-            .carts
-            .iter()
-            .map(|cart| (cart.position, cart))
-            .collect();
-
-        for row in min_row..=max_row {
-            for column in min_column..=max_column {
-                let coords = Coords(row, column);
-                print!(
-                    "{}",
-                    match (carts.get(&coords), self.fields.get(&coords)) {
-                        (Some(cart), _) => cart.to_char(),
-                        (_, None) => ' ',
-                        (_, Some(field)) => field.to_char(),
-                    }
-                );
-            }
-            println!();
-        }
-        println!("{:?}", self.carts);
-    }
-
     fn advance_tick(&mut self) -> (usize, Vec<Coords>) {
-        let mut carts_left = 0;
         let mut collisions = Vec::new();
         self.carts.sort();
         let mut busy_coords: HashMap<Coords, usize> = self
@@ -368,52 +326,215 @@ impl Map {
                         }
                     }
                 }
-                if !self.carts[i].crashed {
-                    carts_left += 1;
-                }
             }
         }
+        // carts which already moved can still be hit later in the tick
+        let carts_left = self.carts.iter().filter(|cart| !cart.crashed).count();
         (carts_left, collisions)
     }
 }
 
-fn main() -> io::Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
+/// Draws the tracks with the carts on them, crashed ones as `X`.
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows = self.fields.keys().map(|Coords(row, _)| row + 1).max();
+        let columns = self.fields.keys().map(|Coords(_, column)| column + 1).max();
+        let carts: HashMap<Coords, &Cart> = self
+            .carts
+            .iter()
+            .map(|cart| (cart.position, cart))
+            .collect();
 
+        for row in 0..rows.unwrap_or(0) {
+            let line: String = (0..columns.unwrap_or(0))
+                .map(|column| {
+                    let coords = Coords(row, column);
+                    match (carts.get(&coords), self.fields.get(&coords)) {
+                        (Some(cart), _) => cart.to_char(),
+                        (_, None) => ' ',
+                        (_, Some(field)) => field.to_char(),
+                    }
+                })
+                .collect();
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+fn parse(input: &[Vec<char>]) -> Map {
     let input_map: HashMap<Coords, Field> = input
-        .split('\n')
-        .filter(|line| !line.is_empty())
+        .iter()
         .enumerate()
         .flat_map(|(row, line)| {
-            repeat(row)
-                .zip(0..(line.len()))
-                .zip(line.chars().map(Field::from_char))
+            line.iter()
+                .enumerate()
+                .filter_map(move |(column, c)| Some((Coords(row, column), Field::from_char(*c)?)))
         })
-        .filter(|(_, field)| field.is_some())
-        .map(|((row, column), field)| (Coords(row, column), field.unwrap()))
         .collect();
 
-    let mut map = Map::from_hashmap(&input_map);
+    Map::from_hashmap(&input_map)
+}
+
+/// Runs the carts until at most one is left, returns the first crash site and the last cart
+/// (if there's any left).
+fn simulate(input: &[Vec<char>]) -> (Coords, Option<Coords>) {
+    let mut map = parse(input);
+    let mut first_collision = None;
 
     loop {
-        // map.print_out();
         let (carts_left, collisions) = map.advance_tick();
-        if !collisions.is_empty() {
-            println!("collision: {},{}", collisions[0].1, collisions[0].0);
+        if first_collision.is_none() {
+            first_collision = collisions.first().copied();
         }
         if carts_left < 2 {
-            map.print_out();
-            println!(
-                "{:?}",
-                map.carts
-                    .iter()
-                    .filter(|cart| !cart.crashed)
-                    .collect::<Vec<_>>()
+            let last_cart = map.carts.iter().find(|cart| !cart.crashed);
+            return (
+                first_collision.expect("there should be a collision"),
+                last_cart.map(|cart| cart.position),
             );
-            break;
         }
     }
+}
+
+impl Problem for Day13 {
+    type Input = RowsOfChars<char>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        let Coords(row, column) = simulate(input).0;
+        format!("{column},{row}")
+    }
 
-    Ok(())
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        let Coords(row, column) = simulate(input).1.expect("there should be a cart left");
+        format!("{column},{row}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use crate::parse::CheckInput;
+    use aoc_helpers::scaffold::{solve_part1, solve_part2};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// Moves the carts tick by tick straight on the characters, gives up after `max_ticks`.
+    ///
+    /// Returns the first crash and the last cart standing (as `x,y`), if they happen in time.
+    fn brute_force(input: &str, max_ticks: usize) -> (Option<String>, Option<String>) {
+        let mut grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        // (row, column, row delta, column delta, crossings passed, crashed)
+        let mut carts = Vec::new();
+        for (row, line) in grid.iter_mut().enumerate() {
+            for (column, c) in line.iter_mut().enumerate() {
+                let (dr, dc, track) = match *c {
+                    '^' => (-1, 0, '|'),
+                    'v' => (1, 0, '|'),
+                    '<' => (0, -1, '-'),
+                    '>' => (0, 1, '-'),
+                    _ => continue,
+                };
+                *c = track;
+                carts.push((row as isize, column as isize, dr, dc, 0, false));
+            }
+        }
+
+        let mut first_crash = None;
+        for _ in 0..max_ticks {
+            carts.sort_unstable();
+            for i in 0..carts.len() {
+                let (row, column, dr, dc, crossings, crashed) = carts[i];
+                if crashed {
+                    continue;
+                }
+                let (row, column) = (row + dr, column + dc);
+                let (dr, dc, crossings) = match grid[row as usize][column as usize] {
+                    '/' => (-dc, -dr, crossings),
+                    '\\' => (dc, dr, crossings),
+                    '+' => match crossings % 3 {
+                        0 => (-dc, dr, crossings + 1),
+                        1 => (dr, dc, crossings + 1),
+                        _ => (dc, -dr, crossings + 1),
+                    },
+                    _ => (dr, dc, crossings),
+                };
+                carts[i] = (row, column, dr, dc, crossings, false);
+                if let Some(other) = (0..carts.len())
+                    .find(|&j| j != i && !carts[j].5 && (carts[j].0, carts[j].1) == (row, column))
+                {
+                    carts[i].5 = true;
+                    carts[other].5 = true;
+                    first_crash.get_or_insert(format!("{column},{row}"));
+                }
+            }
+            carts.retain(|cart| !cart.5);
+            if carts.len() < 2 {
+                let last = carts.first().map(|cart| format!("{},{}", cart.1, cart.0));
+                return (first_crash, last);
+            }
+        }
+        (first_crash, None)
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(
+            solve_part1::<Day13>(include_str!("../../inputs/day13-example.txt")),
+            "7,3"
+        );
+        assert_eq!(
+            solve_part2::<Day13>(include_str!("../../inputs/day13-example2.txt")),
+            "6,4"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let sample = include_str!("../../inputs/day13-example.txt");
+        let map = parse(&RowsOfChars::<char>::parse_checked(sample).unwrap());
+        assert_eq!(
+            map.to_string(),
+            sample
+                .lines()
+                .map(|line| format!("{}\n", line.trim_end()))
+                .collect::<String>()
+        );
+
+        let input = "/------\\\n|      |\n\\>->-<-/\n";
+        let mut map = parse(&RowsOfChars::<char>::parse_checked(input).unwrap());
+        map.advance_tick();
+        assert_eq!(map.to_string(), "/------\\\n|      |\n\\->-X--/\n");
+    }
+
+    #[test]
+    fn test_cart_hit_after_moving() {
+        // the first cart is the last one standing, the second one moves and is then hit by
+        // the third in the same tick
+        let input = "\
+/------\\
+|      |
+\\>->-<-/
+";
+        assert_eq!(solve_part1::<Day13>(input), "4,2");
+        assert_eq!(solve_part2::<Day13>(input), "2,2");
+    }
+
+    #[test]
+    fn test_random_tracks() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let mut checked = 0;
+        while checked < 30 {
+            let input = gen::cart_tracks(&mut rng, 4, 5);
+            // the solver runs until a single cart is left, which might never happen
+            if let (Some(first_crash), Some(last_cart)) = brute_force(&input, 2000) {
+                assert_eq!(solve_part1::<Day13>(&input), first_crash, "{input}");
+                assert_eq!(solve_part2::<Day13>(&input), last_cart, "{input}");
+                checked += 1;
+            }
+        }
+    }
 }
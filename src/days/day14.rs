@@ -0,0 +1,267 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::ParseError;
+
+pub struct Day14;
+
+/// Digits as written in the input, leading zeros included.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Digits(Vec<u8>);
+
+impl FromStr for Digits {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::at(s, s, "expected digits"));
+        }
+        s.char_indices()
+            .map(|(idx, c)| match c.to_digit(10) {
+                Some(digit) => Ok(digit as u8),
+                None => Err(ParseError::at(s, &s[idx..], "expected a digit")),
+            })
+            .collect::<Result<_, _>>()
+            .map(Digits)
+    }
+}
+
+impl Digits {
+    fn value(&self) -> usize {
+        self.0
+            .iter()
+            .fold(0, |value, digit| value * 10 + *digit as usize)
+    }
+}
+
+/// The scoreboard, a digit per recipe.
+pub struct Recipes {
+    scores: Vec<u8>,
+    elves: [usize; 2],
+}
+
+impl Default for Recipes {
+    fn default() -> Self {
+        Self {
+            scores: vec![3, 7],
+            elves: [0, 1],
+        }
+    }
+}
+
+impl Recipes {
+    pub fn scores(&self) -> &[u8] {
+        &self.scores
+    }
+
+    /// Makes one or two new recipes.
+    pub fn step(&mut self) {
+        let new = self.scores[self.elves[0]] + self.scores[self.elves[1]];
+        if new >= 10 {
+            self.scores.push(new / 10);
+        }
+        self.scores.push(new % 10);
+        for elf in &mut self.elves {
+            *elf = (*elf + self.scores[*elf] as usize + 1) % self.scores.len();
+        }
+    }
+
+    /// Keeps making recipes until there are at least `count` of them.
+    pub fn make(&mut self, count: usize) {
+        while self.scores.len() < count {
+            self.step();
+        }
+    }
+
+    /// Number of recipes before the first appearance of each pattern.
+    ///
+    /// Every new score is fed to the matcher one at a time, so a match ending with the first
+    /// of two new recipes isn't missed. Keeps making recipes until all the patterns show
+    /// up.
+    pub fn find(&mut self, patterns: &[&[u8]]) -> Vec<usize> {
+        let matcher = Matcher::new(patterns);
+        let mut found: Vec<Option<usize>> = patterns
+            .iter()
+            .map(|pattern| pattern.is_empty().then_some(0))
+            .collect();
+        let mut left = found.iter().filter(|idx| idx.is_none()).count();
+        let (mut state, mut idx) = (0, 0);
+        while left > 0 {
+            if idx == self.scores.len() {
+                self.step();
+            }
+            state = matcher.next(state, self.scores[idx]);
+            idx += 1;
+            for pattern in matcher.matches(state) {
+                if found[*pattern].is_none() {
+                    found[*pattern] = Some(idx - patterns[*pattern].len());
+                    left -= 1;
+                }
+            }
+        }
+        found.into_iter().map(Option::unwrap).collect()
+    }
+}
+
+/// Aho-Corasick automaton over decimal digits, with a transition for every digit from
+/// every state so the search never backtracks.
+struct Matcher {
+    transitions: Vec<[usize; 10]>,
+    /// Indexes of the patterns ending in each state.
+    outputs: Vec<Vec<usize>>,
+}
+
+impl Matcher {
+    fn new(patterns: &[&[u8]]) -> Self {
+        // trie of the patterns, 0 is both the root and "no child yet"
+        let mut transitions = vec![[0; 10]];
+        let mut outputs = vec![Vec::new()];
+        for (idx, pattern) in patterns.iter().enumerate() {
+            if pattern.is_empty() {
+                continue;
+            }
+            let mut state = 0;
+            for digit in pattern.iter() {
+                let digit = *digit as usize;
+                if transitions[state][digit] == 0 {
+                    transitions.push([0; 10]);
+                    outputs.push(Vec::new());
+                    transitions[state][digit] = transitions.len() - 1;
+                }
+                state = transitions[state][digit];
+            }
+            outputs[state].push(idx);
+        }
+
+        // breadth first, a state's fallback is always closer to the root and already done
+        let mut fallbacks = vec![0; transitions.len()];
+        let mut queue: VecDeque<usize> =
+            transitions[0].iter().copied().filter(|s| *s != 0).collect();
+        while let Some(state) = queue.pop_front() {
+            let fallback_outputs = outputs[fallbacks[state]].clone();
+            outputs[state].extend(fallback_outputs);
+            let fallback_transitions = transitions[fallbacks[state]];
+            for (child, fallback) in transitions[state].iter_mut().zip(fallback_transitions) {
+                if *child == 0 {
+                    *child = fallback;
+                } else {
+                    fallbacks[*child] = fallback;
+                    queue.push_back(*child);
+                }
+            }
+        }
+        Self {
+            transitions,
+            outputs,
+        }
+    }
+
+    fn next(&self, state: usize, digit: u8) -> usize {
+        self.transitions[state][digit as usize]
+    }
+
+    fn matches(&self, state: usize) -> &[usize] {
+        &self.outputs[state]
+    }
+}
+
+fn scores_after(count: usize) -> String {
+    let mut recipes = Recipes::default();
+    recipes.make(count + 10);
+    recipes.scores()[count..count + 10]
+        .iter()
+        .map(|score| char::from(b'0' + score))
+        .collect()
+}
+
+fn recipes_before(pattern: &[u8]) -> usize {
+    Recipes::default().find(&[pattern])[0]
+}
+
+impl Problem for Day14 {
+    type Input = VecFromLines<Digits>;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        scores_after(input[0].value())
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        recipes_before(&input[0].0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_helpers::scaffold::{solve_part1, solve_part2};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn digits(s: &str) -> Vec<u8> {
+        s.parse::<Digits>().unwrap().0
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day14>("9"), "5158916779");
+        assert_eq!(solve_part1::<Day14>("2018"), "5941429882");
+        assert_eq!(solve_part2::<Day14>("51589"), 9);
+        assert_eq!(solve_part2::<Day14>("01245"), 5);
+        assert_eq!(solve_part2::<Day14>("92510"), 18);
+        assert_eq!(solve_part2::<Day14>("59414"), 2018);
+    }
+
+    #[test]
+    fn test_overlapping_patterns() {
+        // a naive matcher restarting from scratch on a mismatch misses "1121" in "11121"
+        let patterns = ["1121", "10", "01", "0101", "1111", "3710"].map(digits);
+        let patterns: Vec<&[u8]> = patterns.iter().map(Vec::as_slice).collect();
+        let mut recipes = Recipes::default();
+        let found = recipes.find(&patterns);
+        for (pattern, idx) in patterns.iter().zip(found) {
+            let first = recipes
+                .scores()
+                .windows(pattern.len())
+                .position(|w| w == *pattern);
+            assert_eq!(first, Some(idx), "{pattern:?}");
+        }
+        assert_eq!(Recipes::default().find(&[&[], &[3, 7]]), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_random_patterns() {
+        let mut rng = StdRng::seed_from_u64(14);
+        let mut recipes = Recipes::default();
+        recipes.make(5000);
+        for _ in 0..50 {
+            let patterns: Vec<Vec<u8>> = (0..rng.gen_range(1..5))
+                .map(|_| {
+                    let start = rng.gen_range(0..4990);
+                    recipes.scores()[start..start + rng.gen_range(1..8)].to_vec()
+                })
+                .collect();
+            let patterns: Vec<&[u8]> = patterns.iter().map(Vec::as_slice).collect();
+            let expected: Vec<usize> = patterns
+                .iter()
+                .map(|pattern| {
+                    let mut windows = recipes.scores().windows(pattern.len());
+                    windows.position(|w| w == *pattern).unwrap()
+                })
+                .collect();
+            assert_eq!(Recipes::default().find(&patterns), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = "12a4".parse::<Digits>().unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (3, "expected a digit")
+        );
+    }
+}
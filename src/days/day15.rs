@@ -1,11 +1,10 @@
-use aoc_helpers::{
-    anyhow,
-    scaffold::{solve, Problem, RowsOfChars},
-};
+use aoc_helpers::scaffold::{Problem, RowsOfChars};
 
-struct Day15;
+use crate::parse::ParseError;
 
-enum Tile {
+pub struct Day15;
+
+pub enum Tile {
     Wall,
     Open,
     Goblin,
@@ -13,7 +12,7 @@ enum Tile {
 }
 
 impl TryFrom<char> for Tile {
-    type Error = anyhow::Error;
+    type Error = ParseError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
@@ -21,7 +20,7 @@ impl TryFrom<char> for Tile {
             '.' => Ok(Self::Open),
             'G' => Ok(Self::Goblin),
             'E' => Ok(Self::Elf),
-            _ => Err(anyhow::anyhow!("Can't parse: {:?}", value)),
+            _ => Err(ParseError::new(format!("unknown tile {value:?}"))),
         }
     }
 }
@@ -50,10 +49,6 @@ impl Problem for Day15 {
     }
 }
 
-fn main() {
-    solve::<Day15>(include_str!("../../inputs/day15.txt"));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,7 +58,7 @@ mod tests {
 
     #[test]
     fn test_sample() {
-        assert_eq!(solve_part1::<Day15>(SAMPLE), Default::default());
-        assert_eq!(solve_part2::<Day15>(SAMPLE), Default::default());
+        assert_eq!(solve_part1::<Day15>(SAMPLE), 0);
+        assert_eq!(solve_part2::<Day15>(SAMPLE), 0);
     }
 }
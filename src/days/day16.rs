@@ -0,0 +1,253 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, ParseError};
+
+pub struct Day16;
+
+type Registers = [usize; 4];
+type Instruction = [usize; 4];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Arg {
+    Register,
+    Immediate,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Opcode {
+    Add(Arg),
+    Mul(Arg),
+    Ban(Arg),
+    Bor(Arg),
+    Set(Arg),
+    Gt(Arg, Arg),
+    Eq(Arg, Arg),
+}
+
+impl Opcode {
+    fn execute(&self, regs: &Registers, instr: &Instruction) -> Registers {
+        use self::Opcode::*;
+        let mut new_registers = *regs;
+        new_registers[instr[3]] = match self {
+            Add(arg) => regs[instr[1]] + Opcode::get_arg(regs, arg, instr[2]),
+            Mul(arg) => regs[instr[1]] * Opcode::get_arg(regs, arg, instr[2]),
+            Ban(arg) => regs[instr[1]] & Opcode::get_arg(regs, arg, instr[2]),
+            Bor(arg) => regs[instr[1]] | Opcode::get_arg(regs, arg, instr[2]),
+            Set(arg) => Opcode::get_arg(regs, arg, instr[1]),
+            Gt(arg1, arg2) => {
+                if Opcode::get_arg(regs, arg1, instr[1]) > Opcode::get_arg(regs, arg2, instr[2]) {
+                    1
+                } else {
+                    0
+                }
+            }
+            Eq(arg1, arg2) => {
+                if Opcode::get_arg(regs, arg1, instr[1]) == Opcode::get_arg(regs, arg2, instr[2]) {
+                    1
+                } else {
+                    0
+                }
+            }
+        };
+        new_registers
+    }
+
+    fn get_arg(registers: &Registers, arg: &Arg, num: usize) -> usize {
+        match arg {
+            Arg::Register => registers[num],
+            Arg::Immediate => num,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Line {
+    Before(Registers),
+    Instruction(Instruction),
+    After(Registers),
+    Blank,
+}
+
+fn parse_numbers(line: &str, caps: regex::Captures) -> Result<[usize; 4], ParseError> {
+    Ok([
+        parse_field(line, &caps[1])?,
+        parse_field(line, &caps[2])?,
+        parse_field(line, &caps[3])?,
+        parse_field(line, &caps[4])?,
+    ])
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref BEFORE_RE: Regex =
+                Regex::new(r"Before: *\[(\d+), *(\d+), *(\d+), *(\d+)\]").unwrap();
+            static ref INSTRUCTION_RE: Regex = Regex::new(r"(\d+) +(\d+) +(\d+) +(\d+)").unwrap();
+            static ref AFTER_RE: Regex =
+                Regex::new(r"After: *\[(\d+), *(\d+), *(\d+), *(\d+)\]").unwrap();
+        }
+
+        if s.is_empty() {
+            Ok(Line::Blank)
+        } else if let Some(caps) = BEFORE_RE.captures(s) {
+            Ok(Line::Before(parse_numbers(s, caps)?))
+        } else if let Some(caps) = AFTER_RE.captures(s) {
+            Ok(Line::After(parse_numbers(s, caps)?))
+        } else if let Some(caps) = INSTRUCTION_RE.captures(s) {
+            Ok(Line::Instruction(parse_numbers(s, caps)?))
+        } else {
+            Err(ParseError::at(
+                s,
+                s,
+                "expected `Before: [..]`, `After: [..]` or an instruction",
+            ))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    before: Registers,
+    instruction: Instruction,
+    after: Registers,
+}
+
+fn parse(input: &[Line]) -> (Vec<Entry>, Vec<Instruction>) {
+    let mut lines = input.iter().filter(|line| **line != Line::Blank);
+
+    let mut entries = Vec::new();
+    let mut instructions = Vec::new();
+    while let Some(line) = lines.next() {
+        match *line {
+            Line::Before(before) => match (lines.next(), lines.next()) {
+                (Some(&Line::Instruction(instruction)), Some(&Line::After(after))) => {
+                    entries.push(Entry {
+                        before,
+                        after,
+                        instruction,
+                    });
+                }
+                other => panic!("Incomplete sample after {:?}: {:?}", before, other),
+            },
+            Line::Instruction(instruction) => instructions.push(instruction),
+            other => panic!("Unexpected line: {:?}", other),
+        }
+    }
+    (entries, instructions)
+}
+
+fn all_opcodes() -> Vec<Opcode> {
+    use self::Arg::*;
+    use self::Opcode::*;
+
+    vec![
+        Add(Register),
+        Add(Immediate),
+        Mul(Register),
+        Mul(Immediate),
+        Ban(Register),
+        Ban(Immediate),
+        Bor(Register),
+        Bor(Immediate),
+        Set(Register),
+        Set(Immediate),
+        Gt(Immediate, Register),
+        Gt(Register, Immediate),
+        Gt(Register, Register),
+        Eq(Immediate, Register),
+        Eq(Register, Immediate),
+        Eq(Register, Register),
+    ]
+}
+
+fn matching_opcodes(entry: &Entry) -> HashSet<Opcode> {
+    all_opcodes()
+        .into_iter()
+        .filter(|opcode| opcode.execute(&entry.before, &entry.instruction) == entry.after)
+        .collect()
+}
+
+fn ambiguous_samples(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .filter(|entry| matching_opcodes(entry).len() >= 3)
+        .count()
+}
+
+fn run_program(entries: &[Entry], instructions: &[Instruction]) -> usize {
+    let mut opcode_meaning: HashMap<usize, HashSet<Opcode>> = HashMap::with_capacity(16);
+    let all_opcodes_set: HashSet<Opcode> = all_opcodes().into_iter().collect();
+    for i in 0..16 {
+        opcode_meaning.insert(i, all_opcodes_set.clone());
+    }
+    for entry in entries.iter() {
+        let matching = matching_opcodes(entry);
+        opcode_meaning
+            .entry(entry.instruction[0])
+            .or_default()
+            .retain(|opcode| matching.contains(opcode));
+    }
+
+    // clean up the opcodes
+    // grab the ones that the meaning is known and remove them from other ones
+    let mut opcodes: HashMap<usize, Opcode> = opcode_meaning
+        .iter()
+        .filter(|(_, v)| v.len() == 1)
+        .map(|(k, v)| (*k, *v.iter().next().unwrap()))
+        .collect();
+    while !instructions.is_empty() && opcodes.len() != opcode_meaning.len() {
+        for (code, opcode) in opcodes.iter() {
+            for (k, v) in opcode_meaning.iter_mut() {
+                if k != code {
+                    v.retain(|op| op != opcode);
+                }
+            }
+        }
+        opcodes.extend(
+            opcode_meaning
+                .iter()
+                .filter(|(_, v)| v.len() == 1)
+                .map(|(k, v)| (*k, *v.iter().next().unwrap())),
+        );
+    }
+
+    instructions.iter().fold([0, 0, 0, 0], |regs, instr| {
+        opcodes.get(&instr[0]).unwrap().execute(&regs, instr)
+    })[0]
+}
+
+impl Problem for Day16 {
+    type Input = VecFromLines<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        let (entries, _) = parse(input);
+        ambiguous_samples(&entries)
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        let (entries, instructions) = parse(input);
+        run_program(&entries, &instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_helpers::scaffold::solve_part1;
+
+    const SAMPLE: &str = include_str!("../../inputs/day16-example.txt");
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve_part1::<Day16>(SAMPLE), 1);
+    }
+}
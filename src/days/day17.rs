@@ -1,12 +1,13 @@
+use lazy_static::lazy_static;
+use regex::Regex;
 use std::fmt::{Display, Write};
+use std::str::FromStr;
 
-use aoc_helpers::{
-    anyhow,
-    scaffold::{solve, Problem, VecFromLines},
-};
-use rematch::rematch;
+use aoc_helpers::scaffold::{Problem, VecFromLines};
 
-struct Day17;
+use crate::parse::{parse_field, ParseError};
+
+pub struct Day17;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Tile {
@@ -165,12 +166,17 @@ impl Simulation {
 
                 // below supports water and either side is solid -> check for puddles
                 if zone[THIS_ROW][LEFT] == Tile::Sand || zone[THIS_ROW][RIGHT] == Tile::Sand {
+                    // water running over a hole doesn't stay, it keeps falling through
+                    let spreads = |col| {
+                        self.get(row, col) == Tile::RunningWater
+                            && self.get(row + 1, col).supports_water()
+                    };
                     let mut left = col - 1;
-                    while self.get(row, left) == Tile::RunningWater {
+                    while spreads(left) {
                         left -= 1;
                     }
                     let mut right = col + 1;
-                    while self.get(row, right) == Tile::RunningWater {
+                    while spreads(right) {
                         right += 1;
                     }
 
@@ -210,15 +216,12 @@ impl Simulation {
 }
 
 #[derive(Clone, Copy, Debug)]
-#[rematch]
-enum Line {
-    #[rematch(r"x=(\d+), y=(\d+)..(\d+)")]
+pub enum Line {
     Horizontal {
         x: usize,
         from_y: usize,
         to_y: usize,
     },
-    #[rematch(r"y=(\d+), x=(\d+)..(\d+)")]
     Vertical {
         y: usize,
         from_x: usize,
@@ -226,6 +229,37 @@ enum Line {
     },
 }
 
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref HORIZONTAL_RE: Regex = Regex::new(r"^x=(\d+), y=(\d+)\.\.(\d+)$").unwrap();
+            static ref VERTICAL_RE: Regex = Regex::new(r"^y=(\d+), x=(\d+)\.\.(\d+)$").unwrap();
+        }
+
+        if let Some(caps) = HORIZONTAL_RE.captures(s) {
+            Ok(Line::Horizontal {
+                x: parse_field(s, &caps[1])?,
+                from_y: parse_field(s, &caps[2])?,
+                to_y: parse_field(s, &caps[3])?,
+            })
+        } else if let Some(caps) = VERTICAL_RE.captures(s) {
+            Ok(Line::Vertical {
+                y: parse_field(s, &caps[1])?,
+                from_x: parse_field(s, &caps[2])?,
+                to_x: parse_field(s, &caps[3])?,
+            })
+        } else {
+            Err(ParseError::at(
+                s,
+                s,
+                "expected `x=<x>, y=<from>..<to>` or `y=<y>, x=<from>..<to>`",
+            ))
+        }
+    }
+}
+
 impl Line {
     fn min_x(&self) -> usize {
         match *self {
@@ -263,11 +297,8 @@ impl Problem for Day17 {
 
     fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
         let mut sim = Simulation::from(input.as_slice());
-        // println!("{}", &sim);
         sim.pour(500, 0);
-        let water = sim.count_water();
-        println!("{}", &sim);
-        water
+        sim.count_water()
     }
 
     fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
@@ -277,14 +308,14 @@ impl Problem for Day17 {
     }
 }
 
-fn main() {
-    solve::<Day17>(include_str!("../../inputs/day17.txt"));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gen;
+    use crate::parse::parse_lines;
     use aoc_helpers::scaffold::{solve_part1, solve_part2};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     const SAMPLE: &str = "x=495, y=2..7\ny=7, x=495..501\nx=501, y=3..7\nx=498, y=2..4\nx=506, y=1..2\nx=498, y=10..13\nx=504, y=10..13\ny=13, x=498..504";
 
@@ -293,4 +324,87 @@ mod tests {
         assert_eq!(solve_part1::<Day17>(SAMPLE), 57);
         assert_eq!(solve_part2::<Day17>(SAMPLE), 29);
     }
+
+    #[test]
+    fn test_running_water_over_a_hole() {
+        // water spreading on the floor at y=33 runs off its right end at x=504 and falls all
+        // the way down, none of it is held
+        let input = "y=33, x=498..503\nx=498, y=32..35\nx=505, y=29..34";
+        assert_eq!(solve_part1::<Day17>(input), 12);
+        assert_eq!(solve_part2::<Day17>(input), 0);
+    }
+
+    /// The usual recursive flood, returns whether the water at `(row, col)` is held in place.
+    fn flood(grid: &mut Vec<Vec<Tile>>, row: usize, col: usize) -> bool {
+        if row == grid.len() {
+            return false;
+        }
+        match grid[row][col] {
+            Tile::Sand | Tile::StillWater => return true,
+            Tile::RunningWater => return false,
+            Tile::Empty => grid[row][col] = Tile::RunningWater,
+        }
+        if !flood(grid, row + 1, col) {
+            return false;
+        }
+        let mut held = true;
+        let mut ends = [col, col];
+        for (end, step) in ends.iter_mut().zip([-1, 1]) {
+            loop {
+                let next = (*end as isize + step) as usize;
+                if grid[row][next] == Tile::Sand {
+                    break;
+                }
+                if grid[row][next] == Tile::Empty {
+                    grid[row][next] = Tile::RunningWater;
+                }
+                *end = next;
+                if !flood(grid, row + 1, next) {
+                    held = false;
+                    break;
+                }
+            }
+        }
+        if held {
+            for tile in &mut grid[row][ends[0]..=ends[1]] {
+                *tile = Tile::StillWater;
+            }
+        }
+        held
+    }
+
+    fn brute_force(lines: &[Line]) -> (usize, usize) {
+        let min_x = lines.iter().map(Line::min_x).min().unwrap() - 1;
+        let max_x = lines.iter().map(Line::max_x).max().unwrap() + 1;
+        let min_y = lines.iter().map(Line::min_y).min().unwrap();
+        let max_y = lines.iter().map(Line::max_y).max().unwrap();
+        let mut grid = vec![vec![Tile::Empty; max_x - min_x + 1]; max_y - min_y + 1];
+        for line in lines {
+            for y in line.min_y()..=line.max_y() {
+                for x in line.min_x()..=line.max_x() {
+                    grid[y - min_y][x - min_x] = Tile::Sand;
+                }
+            }
+        }
+        flood(&mut grid, 0, 500 - min_x);
+        let tiles = grid.iter().flatten();
+        (
+            tiles.clone().filter(|tile| tile.is_water()).count(),
+            tiles.filter(|tile| **tile == Tile::StillWater).count(),
+        )
+    }
+
+    #[test]
+    fn test_random_veins() {
+        let mut rng = StdRng::seed_from_u64(17);
+        for _ in 0..50 {
+            let input = gen::clay_veins(&mut rng, 15, 40);
+            let expected = brute_force(&parse_lines::<Line>(&input).unwrap());
+            assert_eq!(
+                (solve_part1::<Day17>(&input), solve_part2::<Day17>(&input)),
+                expected,
+                "{input}"
+            );
+        }
+    }
 }
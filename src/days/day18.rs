@@ -2,24 +2,26 @@ use std::collections::HashMap;
 
 use aoc_helpers::{prelude::*, scaffold::RowsOfChars, tile_map};
 
-struct Day18;
+use crate::parse::ParseError;
+
+pub struct Day18;
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-enum Tile {
+pub enum Tile {
     Open,
     Trees,
     Lumberyard,
 }
 
 impl TryFrom<char> for Tile {
-    type Error = anyhow::Error;
+    type Error = ParseError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             '.' => Ok(Self::Open),
             '|' => Ok(Self::Trees),
             '#' => Ok(Self::Lumberyard),
-            _ => Err(anyhow::anyhow!("Can't parse: {:?}", value)),
+            _ => Err(ParseError::new(format!("unknown tile {value:?}"))),
         }
     }
 }
@@ -100,10 +102,6 @@ impl Problem for Day18 {
     }
 }
 
-fn main() {
-    solve::<Day18>(include_str!("../../inputs/day18.txt"));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
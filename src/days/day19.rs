@@ -1,5 +1,11 @@
 use std::str::FromStr;
 
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, ParseError};
+
+pub struct Day19;
+
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 enum Arg {
     Register,
@@ -33,9 +39,10 @@ enum Opcode {
 }
 
 impl FromStr for Opcode {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let arg = |c| Arg::from_char(c).ok_or(());
+        let error = || ParseError::new(format!("unknown opcode {s:?}"));
+        let arg = |c| Arg::from_char(c).ok_or_else(error);
         let mut chars = s.chars();
         let mut next_char = || chars.next();
         match (next_char(), next_char(), next_char(), next_char()) {
@@ -44,28 +51,45 @@ impl FromStr for Opcode {
             (Some('s'), Some('e'), Some('t'), Some(c)) => Ok(Opcode::Set(arg(c)?)),
             (Some('g'), Some('t'), Some(c1), Some(c2)) => Ok(Opcode::Gt(arg(c1)?, arg(c2)?)),
             (Some('e'), Some('q'), Some(c1), Some(c2)) => Ok(Opcode::Eq(arg(c1)?, arg(c2)?)),
-            _ => Err(()),
+            _ => Err(error()),
         }
     }
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-struct Instruction {
+pub struct Instruction {
     opcode: Opcode,
     args: [usize; 3],
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split(' ');
-        let opcode: Opcode = parts.next().unwrap().parse()?;
-        let args = [
-            parts.next().unwrap().parse().map_err(|_| ())?,
-            parts.next().unwrap().parse().map_err(|_| ())?,
-            parts.next().unwrap().parse().map_err(|_| ())?,
-        ];
-        Ok(Self { opcode, args })
+        match s.split(' ').collect::<Vec<_>>()[..] {
+            [opcode, a, b, c] => Ok(Self {
+                opcode: opcode
+                    .parse()
+                    .map_err(|e: ParseError| ParseError::at(s, opcode, e.message))?,
+                args: [parse_field(s, a)?, parse_field(s, b)?, parse_field(s, c)?],
+            }),
+            _ => Err(ParseError::at(s, s, "expected `<opcode> <a> <b> <c>`")),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Line {
+    Ip(usize),
+    Instruction(Instruction),
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("#ip ") {
+            Some(ip) => Ok(Line::Ip(parse_field(s, ip)?)),
+            None => Ok(Line::Instruction(s.parse()?)),
+        }
     }
 }
 
@@ -93,19 +117,16 @@ impl Instruction {
     }
 }
 
-fn parse(input: &str) -> (usize, Vec<Instruction>) {
-    let mut lines = input.lines();
-    let first_line = lines.next().unwrap();
-    (
-        first_line
-            .strip_prefix("#ip ")
-            .unwrap()
-            .parse::<usize>()
-            .unwrap(),
-        lines
-            .map(|line| line.parse::<Instruction>().unwrap())
-            .collect::<Vec<Instruction>>(),
-    )
+fn parse(input: &[Line]) -> (usize, Vec<Instruction>) {
+    let mut ip_idx = None;
+    let mut instructions = Vec::new();
+    for line in input {
+        match line {
+            Line::Ip(ip) => ip_idx = Some(*ip),
+            Line::Instruction(instruction) => instructions.push(*instruction),
+        }
+    }
+    (ip_idx.expect("missing #ip declaration"), instructions)
 }
 
 fn execute_zeroed(ip_idx: usize, instructions: &[Instruction]) -> [usize; 6] {
@@ -123,35 +144,51 @@ fn execute(mut regs: [usize; 6], ip_idx: usize, instructions: &[Instruction]) ->
 fn hacked_calc(c: usize) -> usize {
     let mut a = 0;
     for b in 1..=c {
-        if c % b == 0 {
+        if c.is_multiple_of(b) {
             a += c / b;
         }
     }
     a
 }
 
-fn main() {
-    let (ip_idx, mut instructions) = parse(include_str!("../../inputs/day19.txt"));
-    let regs = execute_zeroed(ip_idx, &instructions);
-    println!("Part 1: {}", regs[0]);
-
-    // exit after initial processing and used handcrafted implementation instead
-    instructions[1] = Instruction {
-        opcode: Opcode::Set(Arg::Immediate),
-        args: [100, 100, ip_idx],
-    };
-    let regs = execute([1, 0, 0, 0, 0, 0], ip_idx, &instructions);
-    println!("Part 2: {}", hacked_calc(regs[2]));
+impl Problem for Day19 {
+    type Input = VecFromLines<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        let (ip_idx, instructions) = parse(input);
+        execute_zeroed(ip_idx, &instructions)[0]
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        let (ip_idx, mut instructions) = parse(input);
+
+        // exit after initial processing and used handcrafted implementation instead
+        instructions[1] = Instruction {
+            opcode: Opcode::Set(Arg::Immediate),
+            args: [100, 100, ip_idx],
+        };
+        let regs = execute([1, 0, 0, 0, 0, 0], ip_idx, &instructions);
+        hacked_calc(regs[2])
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gen;
+    use crate::parse::parse_lines;
+    use aoc_helpers::scaffold::solve_part1;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const SAMPLE: &str = include_str!("../../inputs/day19-example.txt");
 
     #[test]
     fn test_parse_sample() {
         assert_eq!(
-            parse(include_str!("../../inputs/day19-example.txt")),
+            parse(&parse_lines::<Line>(SAMPLE).unwrap()),
             (
                 0,
                 vec![
@@ -188,10 +225,36 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let error = "seti 5 x 1".parse::<Instruction>().unwrap_err();
+        assert_eq!(error.column, 8);
+        let error = "setx 5 0 1".parse::<Instruction>().unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (1, "unknown opcode \"setx\"")
+        );
+        assert!("seti 5 0".parse::<Instruction>().is_err());
+    }
+
     #[test]
     fn test_execute_sample() {
-        let (ip_idx, instructions) = parse(include_str!("../../inputs/day19-example.txt"));
-        let regs = execute_zeroed(ip_idx, &instructions);
-        assert_eq!(regs[0], 7);
+        assert_eq!(solve_part1::<Day19>(SAMPLE), 7);
+    }
+
+    #[test]
+    fn test_random_programs() {
+        const OPCODES: &[&str] = &[
+            "addr", "addi", "muli", "setr", "seti", "gtir", "gtri", "gtrr", "eqir", "eqri", "eqrr",
+        ];
+        let mut rng = StdRng::seed_from_u64(19);
+        for _ in 0..100 {
+            let program = gen::ip_program(&mut rng, 25, OPCODES);
+            assert_eq!(
+                solve_part1::<Day19>(&program),
+                gen::run_ip_program(&program)[0],
+                "{program}"
+            );
+        }
     }
 }
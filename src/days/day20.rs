@@ -0,0 +1,282 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::ParseError;
+
+pub struct Day20;
+
+type Pos = (isize, isize);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'N' => Some(Self::North),
+            'S' => Some(Self::South),
+            'E' => Some(Self::East),
+            'W' => Some(Self::West),
+            _ => None,
+        }
+    }
+
+    fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::South => Self::North,
+            Self::East => Self::West,
+            Self::West => Self::East,
+        }
+    }
+
+    fn step(self, (x, y): Pos) -> Pos {
+        match self {
+            Self::North => (x, y - 1),
+            Self::South => (x, y + 1),
+            Self::East => (x + 1, y),
+            Self::West => (x - 1, y),
+        }
+    }
+
+    fn bit(self) -> u8 {
+        match self {
+            Self::North => 1,
+            Self::South => 2,
+            Self::East => 4,
+            Self::West => 8,
+        }
+    }
+}
+
+/// Rooms reachable from the origin together with the doors leading out of each of them.
+#[derive(Debug, Default)]
+pub struct Map {
+    doors: HashMap<Pos, u8>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let route = s
+            .trim()
+            .strip_prefix('^')
+            .and_then(|s| s.strip_suffix('$'))
+            .ok_or_else(|| ParseError::at(s, s, "route should be wrapped in ^...$"))?;
+        let error = |idx: usize, message: String| ParseError::at(s, &route[idx..], message);
+
+        let mut map = Map::default();
+        map.doors.insert((0, 0), 0);
+
+        // instead of expanding every branch we follow the set of rooms we could currently be in,
+        // for each open group we keep the rooms where it started and where its branches ended
+        let mut current: HashSet<Pos> = [(0, 0)].into_iter().collect();
+        let mut groups: Vec<(HashSet<Pos>, HashSet<Pos>)> = Vec::new();
+        for (idx, c) in route.char_indices() {
+            match c {
+                '(' => groups.push((current.clone(), HashSet::new())),
+                '|' => {
+                    let (starts, ends) = groups
+                        .last_mut()
+                        .ok_or_else(|| error(idx, "unexpected '|'".to_string()))?;
+                    ends.extend(current.drain());
+                    current = starts.clone();
+                }
+                ')' => {
+                    let (_, mut ends) = groups
+                        .pop()
+                        .ok_or_else(|| error(idx, "unexpected ')'".to_string()))?;
+                    ends.extend(current.drain());
+                    current = ends;
+                }
+                _ => {
+                    let direction = Direction::from_char(c)
+                        .ok_or_else(|| error(idx, format!("unexpected {c:?}")))?;
+                    current = current
+                        .into_iter()
+                        .map(|pos| map.add_door(pos, direction))
+                        .collect();
+                }
+            }
+        }
+        if groups.is_empty() {
+            Ok(map)
+        } else {
+            Err(error(
+                route.len(),
+                format!("{} unclosed group(s)", groups.len()),
+            ))
+        }
+    }
+}
+
+impl Map {
+    fn add_door(&mut self, pos: Pos, direction: Direction) -> Pos {
+        let next = direction.step(pos);
+        *self.doors.entry(pos).or_default() |= direction.bit();
+        *self.doors.entry(next).or_default() |= direction.opposite().bit();
+        next
+    }
+
+    fn has_door(&self, pos: Pos, direction: Direction) -> bool {
+        self.doors
+            .get(&pos)
+            .map(|doors| doors & direction.bit() != 0)
+            .unwrap_or(false)
+    }
+
+    fn distances(&self) -> HashMap<Pos, usize> {
+        let mut distances = HashMap::with_capacity(self.doors.len());
+        let mut queue = VecDeque::new();
+        distances.insert((0, 0), 0);
+        queue.push_back((0, 0));
+        while let Some(pos) = queue.pop_front() {
+            let distance = distances[&pos];
+            for direction in Direction::ALL {
+                if self.has_door(pos, direction) {
+                    let next = direction.step(pos);
+                    distances.entry(next).or_insert_with(|| {
+                        queue.push_back(next);
+                        distance + 1
+                    });
+                }
+            }
+        }
+        distances
+    }
+
+    fn furthest_room(&self) -> usize {
+        self.distances().into_values().max().unwrap_or_default()
+    }
+
+    fn rooms_at_least(&self, doors: usize) -> usize {
+        self.distances()
+            .into_values()
+            .filter(|distance| *distance >= doors)
+            .count()
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let min_x = self.doors.keys().map(|(x, _)| *x).min().unwrap_or_default();
+        let max_x = self.doors.keys().map(|(x, _)| *x).max().unwrap_or_default();
+        let min_y = self.doors.keys().map(|(_, y)| *y).min().unwrap_or_default();
+        let max_y = self.doors.keys().map(|(_, y)| *y).max().unwrap_or_default();
+
+        let width = ((max_x - min_x + 1) * 2 + 1) as usize;
+        writeln!(f, "{}", "#".repeat(width))?;
+        for y in min_y..=max_y {
+            let mut rooms = String::from("#");
+            let mut below = String::from("#");
+            for x in min_x..=max_x {
+                rooms.push(match (x, y) {
+                    (0, 0) => 'X',
+                    pos if self.doors.contains_key(&pos) => '.',
+                    _ => '#',
+                });
+                rooms.push(if self.has_door((x, y), Direction::East) {
+                    '|'
+                } else {
+                    '#'
+                });
+                below.push(if self.has_door((x, y), Direction::South) {
+                    '-'
+                } else {
+                    '#'
+                });
+                below.push('#');
+            }
+            writeln!(f, "{rooms}")?;
+            writeln!(f, "{below}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Problem for Day20 {
+    type Input = VecFromLines<Map>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        input[0].furthest_room()
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        input[0].rooms_at_least(1000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_furthest_room() {
+        let cases = [
+            ("^WNE$", 3),
+            ("^ENWWW(NEEE|SSE(EE|N))$", 10),
+            ("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$", 18),
+            ("^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$", 23),
+            (
+                "^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$",
+                31,
+            ),
+        ];
+        for (route, expected) in cases {
+            let map: Map = route.parse().unwrap();
+            assert_eq!(map.furthest_room(), expected, "{route}");
+        }
+    }
+
+    #[test]
+    fn test_rooms_at_least() {
+        let map: Map = "^ENWWW(NEEE|SSE(EE|N))$".parse().unwrap();
+        assert_eq!(map.rooms_at_least(0), 16);
+        assert_eq!(map.rooms_at_least(10), 1);
+    }
+
+    #[test]
+    fn test_render() {
+        let map: Map = "^ENWWW(NEEE|SSE(EE|N))$".parse().unwrap();
+        assert_eq!(
+            map.to_string(),
+            "#########\n\
+             #.|.|.|.#\n\
+             #-#######\n\
+             #.|.|.|.#\n\
+             #-#####-#\n\
+             #.#.#X|.#\n\
+             #-#-#####\n\
+             #.|.|.|.#\n\
+             #########\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!("WNE".parse::<Map>().is_err());
+        assert!("^WN(E$".parse::<Map>().is_err());
+        assert!("^WN)E$".parse::<Map>().is_err());
+        assert!("^WNX$".parse::<Map>().is_err());
+        assert_eq!("^WN(E|S)X$".parse::<Map>().unwrap_err().column, 9);
+        assert_eq!("^WN(E$".parse::<Map>().unwrap_err().column, 6);
+    }
+}
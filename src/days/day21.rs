@@ -1,5 +1,11 @@
 use std::{cell::RefCell, collections::HashSet, str::FromStr};
 
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, ParseError};
+
+pub struct Day21;
+
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 enum Arg {
     Register,
@@ -65,11 +71,12 @@ impl Trap {
 }
 
 impl FromStr for Opcode {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let arg = |c| Arg::from_char(c).ok_or(());
+        let error = || ParseError::new(format!("unknown opcode {s:?}"));
+        let arg = |c| Arg::from_char(c).ok_or_else(error);
         let mut chars = s.chars();
-        let mut next_char = || chars.next().ok_or(());
+        let mut next_char = || chars.next().ok_or_else(error);
         match (next_char()?, next_char()?, next_char()?, next_char()?) {
             ('a', 'd', 'd', c) => Ok(Opcode::Add(arg(c)?)),
             ('m', 'u', 'l', c) => Ok(Opcode::Mul(arg(c)?)),
@@ -78,28 +85,45 @@ impl FromStr for Opcode {
             ('s', 'e', 't', c) => Ok(Opcode::Set(arg(c)?)),
             ('g', 't', c1, c2) => Ok(Opcode::Gt(arg(c1)?, arg(c2)?)),
             ('e', 'q', c1, c2) => Ok(Opcode::Eq(arg(c1)?, arg(c2)?)),
-            _ => Err(()),
+            _ => Err(error()),
         }
     }
 }
 
 #[derive(Clone, Debug)]
-struct Instruction {
+pub struct Instruction {
     opcode: Opcode,
     args: [usize; 3],
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split(' ');
-        let opcode: Opcode = parts.next().unwrap().parse()?;
-        let args = [
-            parts.next().unwrap().parse().map_err(|_| ())?,
-            parts.next().unwrap().parse().map_err(|_| ())?,
-            parts.next().unwrap().parse().map_err(|_| ())?,
-        ];
-        Ok(Self { opcode, args })
+        match s.split(' ').collect::<Vec<_>>()[..] {
+            [opcode, a, b, c] => Ok(Self {
+                opcode: opcode
+                    .parse()
+                    .map_err(|e: ParseError| ParseError::at(s, opcode, e.message))?,
+                args: [parse_field(s, a)?, parse_field(s, b)?, parse_field(s, c)?],
+            }),
+            _ => Err(ParseError::at(s, s, "expected `<opcode> <a> <b> <c>`")),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Line {
+    Ip(usize),
+    Instruction(Instruction),
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("#ip ") {
+            Some(ip) => Ok(Line::Ip(parse_field(s, ip)?)),
+            None => Ok(Line::Instruction(s.parse()?)),
+        }
     }
 }
 
@@ -130,19 +154,16 @@ impl Instruction {
     }
 }
 
-fn parse(input: &str) -> (usize, Vec<Instruction>) {
-    let mut lines = input.lines();
-    let first_line = lines.next().unwrap();
-    (
-        first_line
-            .strip_prefix("#ip ")
-            .unwrap()
-            .parse::<usize>()
-            .unwrap(),
-        lines
-            .map(|line| line.parse::<Instruction>().unwrap())
-            .collect::<Vec<Instruction>>(),
-    )
+fn parse(input: &[Line]) -> (usize, Vec<Instruction>) {
+    let mut ip_idx = None;
+    let mut instructions = Vec::new();
+    for line in input {
+        match line {
+            Line::Ip(ip) => ip_idx = Some(*ip),
+            Line::Instruction(instruction) => instructions.push(instruction.clone()),
+        }
+    }
+    (ip_idx.expect("missing #ip declaration"), instructions)
 }
 
 fn execute(mut regs: [usize; 6], ip_idx: usize, instructions: &[Instruction]) -> [usize; 6] {
@@ -153,33 +174,64 @@ fn execute(mut regs: [usize; 6], ip_idx: usize, instructions: &[Instruction]) ->
     regs
 }
 
-fn main() {
-    let (ip_idx, instructions) = parse(include_str!("../../inputs/day21.txt"));
+impl Problem for Day21 {
+    type Input = VecFromLines<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    {
-        let mut hacked_instructions = instructions.clone();
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        let (ip_idx, mut hacked_instructions) = parse(input);
         if let Opcode::Eq(Arg::Register, Arg::Register) = hacked_instructions[28].opcode {
             hacked_instructions[28] = Instruction {
                 opcode: Opcode::Add(Arg::Immediate),
                 args: [2, 100, 2],
             };
             let regs = execute([0, 0, 0, 0, 0, 0], ip_idx, &hacked_instructions);
-            println!("Part 1: {}", regs[1]);
+            regs[1]
         } else {
             panic!("Can't hack it");
         }
     }
 
-    {
-        let mut hacked_instructions = instructions;
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        let (ip_idx, mut hacked_instructions) = parse(input);
         if let Opcode::Eq(Arg::Register, Arg::Register) = hacked_instructions[28].opcode {
             hacked_instructions[28].opcode = Opcode::Trap(Trap::new());
             execute([0, 0, 0, 0, 0, 0], ip_idx, &hacked_instructions);
             if let Opcode::Trap(trap) = &hacked_instructions[28].opcode {
-                println!("Part 2: {}", trap.last_before_seen.borrow());
+                *trap.last_before_seen.borrow()
+            } else {
+                unreachable!("the trap was just installed")
             }
         } else {
             panic!("Can't hack it");
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use crate::parse::parse_lines;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_random_programs() {
+        const OPCODES: &[&str] = &[
+            "addr", "addi", "muli", "banr", "bani", "borr", "bori", "setr", "seti", "gtir", "gtri",
+            "gtrr", "eqir", "eqri", "eqrr",
+        ];
+        let mut rng = StdRng::seed_from_u64(21);
+        for _ in 0..100 {
+            let program = gen::ip_program(&mut rng, 25, OPCODES);
+            let (ip_idx, instructions) = parse(&parse_lines::<Line>(&program).unwrap());
+            assert_eq!(
+                execute([0; 6], ip_idx, &instructions),
+                gen::run_ip_program(&program),
+                "{program}"
+            );
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, parse_lines, ParseError};
+use crate::search::astar;
+
+pub struct Day22;
+
+type Pos = (usize, usize);
+
+const TOOL_SWITCH_TIME: usize = 7;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Region {
+    Rocky,
+    Wet,
+    Narrow,
+}
+
+impl Region {
+    fn from_erosion_level(level: usize) -> Self {
+        match level % 3 {
+            0 => Self::Rocky,
+            1 => Self::Wet,
+            _ => Self::Narrow,
+        }
+    }
+
+    fn risk(self) -> usize {
+        match self {
+            Self::Rocky => 0,
+            Self::Wet => 1,
+            Self::Narrow => 2,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Self::Rocky => '.',
+            Self::Wet => '=',
+            Self::Narrow => '|',
+        }
+    }
+
+    fn allows(self, tool: Tool) -> bool {
+        !matches!(
+            (self, tool),
+            (Self::Rocky, Tool::Neither)
+                | (Self::Wet, Tool::Torch)
+                | (Self::Narrow, Tool::ClimbingGear)
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Tool {
+    Torch,
+    ClimbingGear,
+    Neither,
+}
+
+impl Tool {
+    const ALL: [Tool; 3] = [Tool::Torch, Tool::ClimbingGear, Tool::Neither];
+}
+
+/// Erosion levels are only calculated when asked for and then kept around, the grid grows
+/// row by row and column by column so even far away coordinates don't need any recursion.
+#[derive(Debug)]
+pub struct Cave {
+    depth: usize,
+    target: Pos,
+    erosion_levels: Vec<Vec<usize>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CaveLine {
+    Depth(usize),
+    Target(Pos),
+}
+
+impl FromStr for CaveLine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(depth) = s.strip_prefix("depth: ") {
+            Ok(CaveLine::Depth(parse_field(s, depth)?))
+        } else if let Some(target) = s.strip_prefix("target: ") {
+            let (x, y) = target
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(s, target, "expected `<x>,<y>`"))?;
+            Ok(CaveLine::Target((parse_field(s, x)?, parse_field(s, y)?)))
+        } else {
+            Err(ParseError::at(
+                s,
+                s,
+                "expected `depth: <number>` or `target: <x>,<y>`",
+            ))
+        }
+    }
+}
+
+impl TryFrom<&[CaveLine]> for Cave {
+    type Error = ParseError;
+
+    fn try_from(lines: &[CaveLine]) -> Result<Self, Self::Error> {
+        match *lines {
+            [CaveLine::Depth(depth), CaveLine::Target(target)] => Ok(Self::new(depth, target)),
+            _ => Err(ParseError::new("expected depth followed by target")),
+        }
+    }
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = parse_lines::<CaveLine>(s)?;
+        Self::try_from(lines.as_slice())
+    }
+}
+
+impl Cave {
+    pub fn new(depth: usize, target: Pos) -> Self {
+        Self {
+            depth,
+            target,
+            erosion_levels: Vec::new(),
+        }
+    }
+
+    fn geologic_index(&self, (x, y): Pos) -> usize {
+        if (x, y) == (0, 0) || (x, y) == self.target {
+            0
+        } else if y == 0 {
+            x * 16807
+        } else if x == 0 {
+            y * 48271
+        } else {
+            self.erosion_levels[y][x - 1] * self.erosion_levels[y - 1][x]
+        }
+    }
+
+    fn extend_to(&mut self, (x, y): Pos) {
+        let width = self
+            .erosion_levels
+            .first()
+            .map_or(x + 1, |row| row.len().max(x + 1));
+        let height = self.erosion_levels.len().max(y + 1);
+        for row in 0..height {
+            if row == self.erosion_levels.len() {
+                self.erosion_levels.push(Vec::with_capacity(width));
+            }
+            for col in self.erosion_levels[row].len()..width {
+                let level = (self.geologic_index((col, row)) + self.depth) % 20183;
+                self.erosion_levels[row].push(level);
+            }
+        }
+    }
+
+    fn erosion_level(&mut self, (x, y): Pos) -> usize {
+        if y >= self.erosion_levels.len() || x >= self.erosion_levels[y].len() {
+            self.extend_to((x, y));
+        }
+        self.erosion_levels[y][x]
+    }
+
+    fn region(&mut self, pos: Pos) -> Region {
+        Region::from_erosion_level(self.erosion_level(pos))
+    }
+
+    fn risk_level(&mut self) -> usize {
+        let (target_x, target_y) = self.target;
+        let mut risk = 0;
+        for y in 0..=target_y {
+            for x in 0..=target_x {
+                risk += self.region((x, y)).risk();
+            }
+        }
+        risk
+    }
+
+    /// Draws the top left `width` x `height` corner like the puzzle does, with the mouth as `M`
+    /// and the target as `T`.
+    pub fn render(&mut self, width: usize, height: usize) -> String {
+        let mut result = String::with_capacity((width + 1) * height);
+        for y in 0..height {
+            for x in 0..width {
+                result.push(match (x, y) {
+                    (0, 0) => 'M',
+                    pos if pos == self.target => 'T',
+                    pos => self.region(pos).to_char(),
+                });
+            }
+            result.push('\n');
+        }
+        result
+    }
+
+    fn fastest_rescue(&mut self) -> usize {
+        let target = self.target;
+        let neighbours = |&(pos, tool): &(Pos, Tool)| {
+            let region = self.region(pos);
+            let mut result = Vec::with_capacity(6);
+            for other in Tool::ALL {
+                if other != tool && region.allows(other) {
+                    result.push(((pos, other), TOOL_SWITCH_TIME));
+                }
+            }
+            let (x, y) = pos;
+            let mut adjacent = vec![(x + 1, y), (x, y + 1)];
+            if x > 0 {
+                adjacent.push((x - 1, y));
+            }
+            if y > 0 {
+                adjacent.push((x, y - 1));
+            }
+            for next in adjacent {
+                if self.region(next).allows(tool) {
+                    result.push(((next, tool), 1));
+                }
+            }
+            result
+        };
+        let heuristic = |&((x, y), tool): &(Pos, Tool)| {
+            let switch = if tool == Tool::Torch {
+                0
+            } else {
+                TOOL_SWITCH_TIME
+            };
+            x.abs_diff(target.0) + y.abs_diff(target.1) + switch
+        };
+        astar(((0, 0), Tool::Torch), neighbours, heuristic, |state| {
+            *state == (target, Tool::Torch)
+        })
+        .expect("target should be reachable")
+    }
+}
+
+impl Problem for Day22 {
+    type Input = VecFromLines<CaveLine>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        Cave::try_from(input.as_slice()).unwrap().risk_level()
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        Cave::try_from(input.as_slice()).unwrap().fastest_rescue()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let cave: Cave = "depth: 510\ntarget: 10,10".parse().unwrap();
+        assert_eq!(cave.depth, 510);
+        assert_eq!(cave.target, (10, 10));
+        let error = "depth: 510\ntarget: 10".parse::<Cave>().unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), 9));
+        assert!("target: 10,10\ndepth: 510".parse::<Cave>().is_err());
+    }
+
+    #[test]
+    fn test_erosion_levels() {
+        let mut cave = Cave::new(510, (10, 10));
+        assert_eq!(cave.erosion_level((0, 0)), 510);
+        assert_eq!(cave.erosion_level((1, 0)), 17317);
+        assert_eq!(cave.erosion_level((0, 1)), 8415);
+        assert_eq!(cave.erosion_level((1, 1)), 1805);
+        assert_eq!(cave.erosion_level((10, 10)), 510);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut cave = Cave::new(510, (10, 10));
+        assert_eq!(
+            cave.render(16, 16),
+            "M=.|=.|.|=.|=|=.\n\
+             .|=|=|||..|.=...\n\
+             .==|....||=..|==\n\
+             =.|....|.==.|==.\n\
+             =|..==...=.|==..\n\
+             =||.=.=||=|=..|=\n\
+             |.=.===|||..=..|\n\
+             |..==||=.|==|===\n\
+             .=..===..=|.|||.\n\
+             .======|||=|=.|=\n\
+             .===|=|===T===||\n\
+             =|||...|==..|=.|\n\
+             =.=|=.=..=.||==|\n\
+             ||=|=...|==.=|==\n\
+             |=.=||===.|||===\n\
+             ||.|==.|.|.||=||\n"
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut cave = Cave::new(510, (10, 10));
+        assert_eq!(cave.risk_level(), 114);
+        assert_eq!(cave.fastest_rescue(), 45);
+    }
+}
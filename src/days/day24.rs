@@ -0,0 +1,346 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, ParseError};
+
+pub struct Day24;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Army {
+    ImmuneSystem,
+    Infection,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Group {
+    army: Army,
+    units: usize,
+    hit_points: usize,
+    weaknesses: Vec<String>,
+    immunities: Vec<String>,
+    damage: usize,
+    attack_type: String,
+    initiative: usize,
+}
+
+impl Group {
+    fn effective_power(&self) -> usize {
+        self.units * self.damage
+    }
+
+    fn damage_to(&self, other: &Group) -> usize {
+        if other.immunities.contains(&self.attack_type) {
+            0
+        } else if other.weaknesses.contains(&self.attack_type) {
+            self.effective_power() * 2
+        } else {
+            self.effective_power()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Outcome {
+    Victory { army: Army, units: usize },
+    Stalemate,
+}
+
+/// A single input line, groups get their army from the closest header above them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Line {
+    Header(Army),
+    Group {
+        units: usize,
+        hit_points: usize,
+        weaknesses: Vec<String>,
+        immunities: Vec<String>,
+        damage: usize,
+        attack_type: String,
+        initiative: usize,
+    },
+    Blank,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref GROUP_RE: Regex = Regex::new(
+                r"^(?P<units>\d+) units each with (?P<hp>\d+) hit points (?:\((?P<modifiers>[^)]*)\) )?with an attack that does (?P<damage>\d+) (?P<type>\w+) damage at initiative (?P<initiative>\d+)$",
+            )
+            .unwrap();
+        }
+
+        match s.trim() {
+            "" => return Ok(Line::Blank),
+            "Immune System:" => return Ok(Line::Header(Army::ImmuneSystem)),
+            "Infection:" => return Ok(Line::Header(Army::Infection)),
+            _ => {}
+        }
+
+        let caps = GROUP_RE
+            .captures(s)
+            .ok_or_else(|| ParseError::at(s, s, "expected an army header or a group"))?;
+        let mut weaknesses = Vec::new();
+        let mut immunities = Vec::new();
+        if let Some(modifiers) = caps.name("modifiers") {
+            // "weak to a, b; immune to c" in either order
+            for clause in modifiers.as_str().split("; ") {
+                let (target, list) = if let Some(list) = clause.strip_prefix("weak to ") {
+                    (&mut weaknesses, list)
+                } else if let Some(list) = clause.strip_prefix("immune to ") {
+                    (&mut immunities, list)
+                } else {
+                    return Err(ParseError::at(
+                        s,
+                        clause,
+                        "expected `weak to ...` or `immune to ...`",
+                    ));
+                };
+                target.extend(list.split(", ").map(ToString::to_string));
+            }
+        }
+        Ok(Line::Group {
+            units: parse_field(s, &caps["units"])?,
+            hit_points: parse_field(s, &caps["hp"])?,
+            weaknesses,
+            immunities,
+            damage: parse_field(s, &caps["damage"])?,
+            attack_type: caps["type"].to_string(),
+            initiative: parse_field(s, &caps["initiative"])?,
+        })
+    }
+}
+
+fn groups(lines: &[Line]) -> Result<Vec<Group>, ParseError> {
+    let mut army = None;
+    let mut groups = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        match line.clone() {
+            Line::Header(header) => army = Some(header),
+            Line::Group {
+                units,
+                hit_points,
+                weaknesses,
+                immunities,
+                damage,
+                attack_type,
+                initiative,
+            } => groups.push(Group {
+                army: army.ok_or_else(|| {
+                    ParseError::new("group before any army header").with_line(idx + 1, "")
+                })?,
+                units,
+                hit_points,
+                weaknesses,
+                immunities,
+                damage,
+                attack_type,
+                initiative,
+            }),
+            Line::Blank => {}
+        }
+    }
+    Ok(groups)
+}
+
+/// Target selection phase, returns `(attacker, defender)` indexes.
+fn select_targets(groups: &[Group]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..groups.len()).collect();
+    order.sort_by_key(|idx| {
+        let group = &groups[*idx];
+        Reverse((group.effective_power(), group.initiative))
+    });
+
+    let mut taken = HashSet::new();
+    let mut targets = Vec::new();
+    for attacker_idx in order {
+        let attacker = &groups[attacker_idx];
+        let target = groups
+            .iter()
+            .enumerate()
+            .filter(|(idx, defender)| defender.army != attacker.army && !taken.contains(idx))
+            .map(|(idx, defender)| {
+                (
+                    attacker.damage_to(defender),
+                    defender.effective_power(),
+                    defender.initiative,
+                    idx,
+                )
+            })
+            .filter(|(damage, _, _, _)| *damage > 0)
+            .max();
+        if let Some((_, _, _, defender_idx)) = target {
+            taken.insert(defender_idx);
+            targets.push((attacker_idx, defender_idx));
+        }
+    }
+    targets
+}
+
+/// Runs a single round, returns how many units were killed.
+fn fight(groups: &mut Vec<Group>) -> usize {
+    let mut targets = select_targets(groups);
+    targets.sort_by_key(|(attacker_idx, _)| Reverse(groups[*attacker_idx].initiative));
+
+    let mut killed_total = 0;
+    for (attacker_idx, defender_idx) in targets {
+        let damage = groups[attacker_idx].damage_to(&groups[defender_idx]);
+        let defender = &mut groups[defender_idx];
+        let killed = (damage / defender.hit_points).min(defender.units);
+        defender.units -= killed;
+        killed_total += killed;
+    }
+    groups.retain(|group| group.units > 0);
+    killed_total
+}
+
+fn battle(mut groups: Vec<Group>) -> Outcome {
+    loop {
+        let armies: HashSet<Army> = groups.iter().map(|group| group.army).collect();
+        if armies.len() < 2 {
+            return match armies.into_iter().next() {
+                Some(army) => Outcome::Victory {
+                    army,
+                    units: groups.iter().map(|group| group.units).sum(),
+                },
+                None => Outcome::Stalemate,
+            };
+        }
+        if fight(&mut groups) == 0 {
+            return Outcome::Stalemate;
+        }
+    }
+}
+
+fn boosted(groups: &[Group], boost: usize) -> Vec<Group> {
+    groups
+        .iter()
+        .cloned()
+        .map(|mut group| {
+            if group.army == Army::ImmuneSystem {
+                group.damage += boost;
+            }
+            group
+        })
+        .collect()
+}
+
+/// Finds the smallest boost for which the immune system wins.
+///
+/// Stalemates make the outcome non-monotonic in the boost so every value is tried in turn.
+fn minimal_boost(groups: &[Group]) -> (usize, usize) {
+    (0..)
+        .find_map(|boost| match battle(boosted(groups, boost)) {
+            Outcome::Victory {
+                army: Army::ImmuneSystem,
+                units,
+            } => Some((boost, units)),
+            _ => None,
+        })
+        .unwrap()
+}
+
+impl Problem for Day24 {
+    type Input = VecFromLines<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        match battle(groups(input).unwrap()) {
+            Outcome::Victory { units, .. } => units,
+            Outcome::Stalemate => panic!("the battle should not end in a stalemate"),
+        }
+    }
+
+    fn solve_part2(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        minimal_boost(&groups(input).unwrap()).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_lines;
+
+    const SAMPLE: &str = "Immune System:
+17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
+989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
+
+Infection:
+801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
+4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4
+";
+
+    fn parse(input: &str) -> Result<Vec<Group>, ParseError> {
+        groups(&parse_lines::<Line>(input)?)
+    }
+
+    #[test]
+    fn test_parse() {
+        let groups = parse(SAMPLE).unwrap();
+        assert_eq!(groups.len(), 4);
+        assert_eq!(
+            groups[1],
+            Group {
+                army: Army::ImmuneSystem,
+                units: 989,
+                hit_points: 1274,
+                weaknesses: vec!["bludgeoning".to_string(), "slashing".to_string()],
+                immunities: vec!["fire".to_string()],
+                damage: 25,
+                attack_type: "slashing".to_string(),
+                initiative: 3,
+            }
+        );
+        assert_eq!(groups[2].army, Army::Infection);
+        assert!(groups[2].immunities.is_empty());
+    }
+
+    #[test]
+    fn test_parse_without_modifiers() {
+        let group = parse(
+            "Infection:\n543 units each with 2286 hit points with an attack that does 34 cold damage at initiative 13",
+        )
+        .unwrap()
+        .remove(0);
+        assert!(group.weaknesses.is_empty());
+        assert!(group.immunities.is_empty());
+        assert_eq!(group.effective_power(), 543 * 34);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("17 units each with 5390 hit points with an attack that does 4507 fire damage at initiative 2")
+            .is_err());
+        let error = parse("Infection:\n17 units each with 5390 hit points (weak fire) with an attack that does 4507 fire damage at initiative 2")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), 37));
+    }
+
+    #[test]
+    fn test_battle() {
+        let groups = parse(SAMPLE).unwrap();
+        assert_eq!(
+            battle(groups.clone()),
+            Outcome::Victory {
+                army: Army::Infection,
+                units: 5216
+            }
+        );
+        assert_eq!(
+            battle(boosted(&groups, 1570)),
+            Outcome::Victory {
+                army: Army::ImmuneSystem,
+                units: 51
+            }
+        );
+        assert_eq!(minimal_boost(&groups), (1570, 51));
+    }
+}
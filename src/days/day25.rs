@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{Problem, VecFromLines};
+
+use crate::parse::{parse_field, parse_lines, ParseError};
+
+use crate::union_find::UnionFind;
+
+pub struct Day25;
+
+const MAX_DISTANCE: isize = 3;
+
+type Point = Vec<isize>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Coordinates(Point);
+
+impl FromStr for Coordinates {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let point = s
+            .trim()
+            .split(',')
+            .map(|coord| parse_field(s, coord.trim()))
+            .collect::<Result<Point, _>>()?;
+        Ok(Self(point))
+    }
+}
+
+fn dist(a: &[isize], b: &[isize]) -> isize {
+    a.iter().zip(b).map(|(a, b)| (a - b).abs()).sum()
+}
+
+/// Number of coordinates every point has, the index of the first point with a different
+/// number than the first one otherwise.
+fn dimensions(points: &[Point]) -> Result<usize, (usize, &'static str)> {
+    let dimensions = points.first().map_or(0, Vec::len);
+    match points.iter().position(|point| point.len() != dimensions) {
+        Some(idx) => Err((
+            idx,
+            "point has a different number of coordinates than the first one",
+        )),
+        None => Ok(dimensions),
+    }
+}
+
+pub fn check(input: &str) -> Result<(), ParseError> {
+    let points: Vec<Point> = parse_lines::<Coordinates>(input)?
+        .into_iter()
+        .map(|Coordinates(point)| point)
+        .collect();
+    dimensions(&points).map(|_| ()).map_err(|(idx, message)| {
+        ParseError::new(message).with_line(idx + 1, input.lines().nth(idx).unwrap())
+    })
+}
+
+/// Offsets to every neighbouring bucket (including the bucket itself) in `dimensions` dimensions.
+fn bucket_offsets(dimensions: usize) -> Vec<Point> {
+    let mut offsets = vec![Vec::with_capacity(dimensions)];
+    for _ in 0..dimensions {
+        offsets = offsets
+            .into_iter()
+            .flat_map(|offset| {
+                (-1..=1).map(move |d| {
+                    let mut offset = offset.clone();
+                    offset.push(d);
+                    offset
+                })
+            })
+            .collect();
+    }
+    offsets
+}
+
+/// Groups points into constellations, returns indexes of points in each of them.
+///
+/// Points are put into buckets of `MAX_DISTANCE` size along each axis so only points in
+/// adjacent buckets have to be compared.
+fn constellations(points: &[Point]) -> Result<Vec<Vec<usize>>, (usize, &'static str)> {
+    let dimensions = dimensions(points)?;
+    let bucket_of = |point: &[isize]| -> Point {
+        point
+            .iter()
+            .map(|coord| coord.div_euclid(MAX_DISTANCE))
+            .collect()
+    };
+    let mut buckets: HashMap<Point, Vec<usize>> = HashMap::new();
+    for (idx, point) in points.iter().enumerate() {
+        buckets.entry(bucket_of(point)).or_default().push(idx);
+    }
+
+    let offsets = bucket_offsets(dimensions);
+    let mut sets = UnionFind::new(points.len());
+    for (idx, point) in points.iter().enumerate() {
+        let bucket = bucket_of(point);
+        for offset in &offsets {
+            let neighbour: Point = bucket.iter().zip(offset).map(|(b, o)| b + o).collect();
+            for other in buckets.get(&neighbour).into_iter().flatten() {
+                if *other > idx && dist(point, &points[*other]) <= MAX_DISTANCE {
+                    sets.union(idx, *other);
+                }
+            }
+        }
+    }
+    Ok(sets.sets())
+}
+
+impl Problem for Day25 {
+    type Input = VecFromLines<Coordinates>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part1 {
+        let points: Vec<Point> = input.iter().map(|Coordinates(p)| p.clone()).collect();
+        constellations(&points)
+            .unwrap_or_else(|(_, message)| panic!("{message}"))
+            .len()
+    }
+
+    fn solve_part2(_input: &<Self::Input as aoc_helpers::scaffold::Parse>::Parsed) -> Self::Part2 {
+        // there's no puzzle for the second part of the last day
+        Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_helpers::scaffold::solve_part1;
+
+    fn count(input: &str) -> usize {
+        solve_part1::<Day25>(input)
+    }
+
+    #[test]
+    fn test_samples() {
+        assert_eq!(
+            count(" 0,0,0,0\n 3,0,0,0\n 0,3,0,0\n 0,0,3,0\n 0,0,0,3\n 0,0,0,6\n 9,0,0,0\n12,0,0,0"),
+            2
+        );
+        assert_eq!(
+            count("-1,2,2,0\n0,0,2,-2\n0,0,0,-2\n-1,2,0,0\n-2,-2,-2,2\n3,0,2,-1\n-1,3,2,2\n-1,0,-1,0\n0,2,1,-2\n3,0,0,0"),
+            4
+        );
+        assert_eq!(
+            count("1,-1,0,1\n2,0,-1,0\n3,2,-1,0\n0,0,3,1\n0,0,-1,-1\n2,3,-2,0\n-2,2,0,0\n2,-2,0,-1\n1,-1,0,-1\n3,2,0,2"),
+            3
+        );
+        assert_eq!(
+            count("1,-1,-1,-2\n-2,-2,0,1\n0,2,1,3\n-2,3,-2,1\n0,2,3,-2\n-1,-1,1,-2\n0,-2,-1,0\n-2,2,3,-1\n1,2,2,0\n-1,-2,0,-2"),
+            8
+        );
+    }
+
+    #[test]
+    fn test_membership() {
+        let points: Vec<Point> = ["0,0", "3,0", "6,0", "10,0", "0,4"]
+            .iter()
+            .map(|line| line.parse::<Coordinates>().unwrap().0)
+            .collect();
+        assert_eq!(
+            constellations(&points),
+            Ok(vec![vec![0, 1, 2], vec![3], vec![4]])
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!("1,2,3,4".parse::<Coordinates>().is_ok());
+        assert_eq!("1,x,3,4".parse::<Coordinates>().unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn test_mixed_dimensions() {
+        assert!(check("0,0,0,0\n3,0,0,0").is_ok());
+        let error = check("0,0,0,0\n3,0,0,0\n0,3,0\n0,0,3,0,1").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (Some(3), "0,3,0"));
+        assert!(check("0,0,0\n0,0,0,0").is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use std::path::PathBuf;
+
+use aoc_helpers::scaffold::{solve_part1, solve_part2, Problem};
+
+use crate::bench::{self, Record, Settings};
+use crate::parse::{CheckInput, ParseError};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day24;
+pub mod day25;
+
+type Solver = fn(&str) -> String;
+
+/// A solved day as seen by the runner, answers are already formatted.
+///
+/// Solvers panic on malformed input, `check` should be run first to get a proper error.
+/// `bench` times parsing and each part separately.
+pub struct Day {
+    pub day: u8,
+    pub check: fn(&str) -> Result<(), ParseError>,
+    pub part1: Solver,
+    pub part2: Option<Solver>,
+    /// Part 2 with the puzzle's parameters given explicitly, examples use smaller ones.
+    pub part2_with: Option<fn(&str, &[usize]) -> String>,
+    pub bench: fn(&str, &Settings) -> Result<Vec<Record>, ParseError>,
+}
+
+impl Day {
+    /// Default input file for the day, `inputs/dayNN.txt`.
+    pub fn input_path(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("inputs")
+            .join(format!("day{:02}.txt", self.day))
+    }
+
+    pub fn solver(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+macro_rules! day {
+    ($day:expr, $problem:ty) => {
+        Day {
+            day: $day,
+            check: <<$problem as Problem>::Input as CheckInput>::check,
+            part1: |input| solve_part1::<$problem>(input).to_string(),
+            part2: Some(|input| solve_part2::<$problem>(input).to_string()),
+            part2_with: None,
+            bench: |input, settings| bench::problem::<$problem>($day, input, settings, true),
+        }
+    };
+    ($day:expr, $problem:ty, part1 only) => {
+        Day {
+            day: $day,
+            check: <<$problem as Problem>::Input as CheckInput>::check,
+            part1: |input| solve_part1::<$problem>(input).to_string(),
+            part2: None,
+            part2_with: None,
+            bench: |input, settings| bench::problem::<$problem>($day, input, settings, false),
+        }
+    };
+    ($day:expr, $problem:ty, part1 only, $($field:ident = $value:expr),+) => {
+        Day {
+            $($field: $value,)+
+            ..day!($day, $problem, part1 only)
+        }
+    };
+    ($day:expr, $problem:ty, $($field:ident = $value:expr),+) => {
+        Day {
+            $($field: $value,)+
+            ..day!($day, $problem)
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, day01::Day01),
+    day!(2, day02::Day02),
+    day!(3, day03::Day03),
+    day!(4, day04::Day04, check = day04::check),
+    day!(5, day05::Day05),
+    day!(6, day06::Day06, part2_with = Some(day06::part2_with)),
+    day!(
+        7,
+        day07::Day07,
+        check = day07::check,
+        part2_with = Some(day07::part2_with)
+    ),
+    day!(8, day08::Day08),
+    day!(9, day09::Day09),
+    day!(10, day10::Day10),
+    day!(11, day11::Day11),
+    day!(12, day12::Day12, check = day12::check),
+    day!(13, day13::Day13),
+    day!(14, day14::Day14),
+    day!(16, day16::Day16),
+    day!(17, day17::Day17),
+    day!(18, day18::Day18),
+    day!(19, day19::Day19),
+    day!(20, day20::Day20),
+    day!(21, day21::Day21),
+    day!(22, day22::Day22),
+    day!(24, day24::Day24),
+    day!(25, day25::Day25, part1 only, check = day25::check),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
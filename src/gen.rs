@@ -0,0 +1,386 @@
+//! Random but valid puzzle inputs, used to cross-check solvers against brute force.
+//!
+//! Every generator returns the input text exactly as it would appear in `inputs/dayNN.txt`.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::days::day04::DAYS_IN_MONTH;
+
+/// Day 3: `count` fabric claims, all fitting within a `size`x`size` square.
+pub fn claims(rng: &mut impl Rng, count: usize, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=count {
+        let width = rng.gen_range(1..=size / 4 + 1);
+        let height = rng.gen_range(1..=size / 4 + 1);
+        let x = rng.gen_range(0..=size - width);
+        let y = rng.gen_range(0..=size - height);
+        writeln!(input, "#{id} @ {x},{y}: {width}x{height}").unwrap();
+    }
+    input
+}
+
+/// Day 4: shuffled log of `nights` shifts of up to `guards` guards, all within a single year.
+pub fn guard_log(rng: &mut impl Rng, nights: usize, guards: usize) -> String {
+    assert!(nights <= 300, "the log has to fit in a year");
+    let ids: Vec<usize> = (0..guards).map(|_| rng.gen_range(1..4000)).collect();
+    let (mut month, mut day) = (rng.gen_range(0..2), rng.gen_range(1..28));
+    let mut lines = Vec::new();
+    for _ in 0..nights {
+        let date = format!("1518-{:02}-{:02}", month + 1, day + 1);
+        let guard = ids.choose(rng).unwrap();
+        if rng.gen_bool(0.5) {
+            // shifts can start just before midnight, the log then has the previous date
+            let (month, day) = if day == 0 {
+                (month - 1, DAYS_IN_MONTH[month - 1] - 1)
+            } else {
+                (month, day - 1)
+            };
+            lines.push(format!(
+                "[1518-{:02}-{:02} 23:{}] Guard #{guard} begins shift",
+                month + 1,
+                day + 1,
+                rng.gen_range(45..60)
+            ));
+        } else {
+            lines.push(format!(
+                "[{date} 00:{:02}] Guard #{guard} begins shift",
+                rng.gen_range(0..5)
+            ));
+        }
+        let mut minute = rng.gen_range(5..15);
+        while minute < 59 && rng.gen_bool(0.7) {
+            let wakes = rng.gen_range(minute + 1..=(minute + 20).min(59));
+            lines.push(format!("[{date} 00:{minute:02}] falls asleep"));
+            lines.push(format!("[{date} 00:{wakes:02}] wakes up"));
+            minute = wakes + 1;
+        }
+
+        day += 1;
+        if day == DAYS_IN_MONTH[month] {
+            month += 1;
+            day = 0;
+        }
+    }
+    lines.shuffle(rng);
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+/// Day 5: polymer of `len` units of the first `kinds` letters, small `kinds` means more reactions.
+pub fn polymer(rng: &mut impl Rng, len: usize, kinds: u8) -> String {
+    let mut polymer: String = (0..len)
+        .map(|_| {
+            let c = (b'a' + rng.gen_range(0..kinds)) as char;
+            if rng.gen_bool(0.5) {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    polymer.push('\n');
+    polymer
+}
+
+/// Day 6: `count` distinct points within a `size`x`size` square.
+pub fn coordinates(rng: &mut impl Rng, count: usize, size: isize) -> String {
+    let mut points = BTreeSet::new();
+    while points.len() < count {
+        points.insert((rng.gen_range(0..size), rng.gen_range(0..size)));
+    }
+    let mut lines: Vec<String> = points
+        .into_iter()
+        .map(|(x, y)| format!("{x}, {y}\n"))
+        .collect();
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+/// Day 7: acyclic dependencies between the first `steps` letters, every step takes part in one.
+pub fn dependencies(rng: &mut impl Rng, steps: usize, extra: usize) -> String {
+    assert!((2..=26).contains(&steps));
+    let mut order: Vec<char> = ('A'..='Z').take(steps).collect();
+    order.shuffle(rng);
+    let mut edges = BTreeSet::new();
+    for to in 1..steps {
+        edges.insert((rng.gen_range(0..to), to));
+    }
+    for _ in 0..extra {
+        let from = rng.gen_range(0..steps - 1);
+        edges.insert((from, rng.gen_range(from + 1..steps)));
+    }
+    let mut lines: Vec<String> = edges
+        .into_iter()
+        .map(|(from, to)| {
+            format!(
+                "Step {} must be finished before step {} can begin.\n",
+                order[from], order[to]
+            )
+        })
+        .collect();
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+/// Day 9: a single game description with at most `max_marble` marbles.
+pub fn marble_game(rng: &mut impl Rng, max_marble: usize) -> String {
+    format!(
+        "{} players; last marble is worth {} points\n",
+        rng.gen_range(1..=30),
+        rng.gen_range(1..=max_marble)
+    )
+}
+
+/// Day 13: up to `loops` rectangular tracks crossing each other and `carts` carts on them.
+///
+/// Corners are kept on even coordinates and tracks never run alongside each other, so the
+/// only places where two tracks meet are `+` intersections.
+pub fn cart_tracks(rng: &mut impl Rng, loops: usize, carts: usize) -> String {
+    let size = 4 * loops + 6;
+    let mut rects: Vec<(usize, usize, usize, usize)> = Vec::new();
+    for _ in 0..loops * 20 {
+        if rects.len() == loops {
+            break;
+        }
+        let mut span = || {
+            let from = rng.gen_range(0..size / 2 - 1);
+            (2 * from, 2 * rng.gen_range(from + 1..size / 2))
+        };
+        let (x1, x2) = span();
+        let (y1, y2) = span();
+        let overlaps = |a: (usize, usize), b: (usize, usize)| a.0 <= b.1 && b.0 <= a.1;
+        let clashes = rects.iter().any(|&(ox1, oy1, ox2, oy2)| {
+            let rows = [y1, y2].iter().any(|y| *y == oy1 || *y == oy2);
+            let columns = [x1, x2].iter().any(|x| *x == ox1 || *x == ox2);
+            (rows && overlaps((x1, x2), (ox1, ox2))) || (columns && overlaps((y1, y2), (oy1, oy2)))
+        });
+        if !clashes {
+            rects.push((x1, y1, x2, y2));
+        }
+    }
+
+    let mut grid = vec![vec![' '; size + 1]; size + 1];
+    for &(x1, y1, x2, y2) in &rects {
+        for y in [y1, y2] {
+            for cell in &mut grid[y][x1 + 1..x2] {
+                *cell = if *cell == '|' { '+' } else { '-' };
+            }
+        }
+        for row in &mut grid[y1 + 1..y2] {
+            for x in [x1, x2] {
+                row[x] = if row[x] == '-' { '+' } else { '|' };
+            }
+        }
+        grid[y1][x1] = '/';
+        grid[y2][x2] = '/';
+        grid[y1][x2] = '\\';
+        grid[y2][x1] = '\\';
+    }
+
+    let mut straights: Vec<(usize, usize)> = (0..=size)
+        .flat_map(|y| (0..=size).map(move |x| (x, y)))
+        .filter(|&(x, y)| matches!(grid[y][x], '-' | '|'))
+        .collect();
+    straights.shuffle(rng);
+    for &(x, y) in straights.iter().take(carts) {
+        let facing = if grid[y][x] == '-' {
+            ['<', '>']
+        } else {
+            ['^', 'v']
+        };
+        grid[y][x] = *facing.choose(rng).unwrap();
+    }
+
+    grid.iter()
+        .map(|row| format!("{}\n", row.iter().collect::<String>().trim_end()))
+        .collect()
+}
+
+/// Day 17: `veins` clay veins, some of them arranged into basins that hold water.
+///
+/// The clay is spread around the spring at `x=500`, which is never directly on top of clay.
+pub fn clay_veins(rng: &mut impl Rng, veins: usize, depth: usize) -> String {
+    loop {
+        let mut lines = Vec::new();
+        let mut top = usize::MAX;
+        let (mut min_x, mut max_x) = (usize::MAX, 0);
+        let mut clay_on_spring = Vec::new();
+        while lines.len() < veins {
+            let y = rng.gen_range(1..=depth);
+            let x = rng.gen_range(480..=520);
+            if rng.gen_bool(0.5) && lines.len() + 3 <= veins {
+                let width = rng.gen_range(2..12);
+                let (left, right) = (x - width / 2, x + width - width / 2);
+                let bottom = y + rng.gen_range(1..8);
+                let (left_top, right_top) = (rng.gen_range(y..bottom), rng.gen_range(y..bottom));
+                (min_x, max_x) = (min_x.min(left), max_x.max(right));
+                lines.push(format!("x={left}, y={left_top}..{bottom}"));
+                lines.push(format!("x={right}, y={right_top}..{bottom}"));
+                lines.push(format!("y={bottom}, x={left}..{right}"));
+                top = top.min(left_top).min(right_top);
+                if (left..=right).contains(&500) {
+                    clay_on_spring.push(bottom);
+                }
+                for (wall, wall_top) in [(left, left_top), (right, right_top)] {
+                    if wall == 500 {
+                        clay_on_spring.push(wall_top);
+                    }
+                }
+            } else if rng.gen_bool(0.5) {
+                let len = rng.gen_range(0..6);
+                (min_x, max_x) = (min_x.min(x), max_x.max(x));
+                lines.push(format!("x={x}, y={y}..{}", y + len));
+                top = top.min(y);
+                if x == 500 {
+                    clay_on_spring.push(y);
+                }
+            } else {
+                let len = rng.gen_range(0..6);
+                (min_x, max_x) = (min_x.min(x), max_x.max(x + len));
+                lines.push(format!("y={y}, x={x}..{}", x + len));
+                top = top.min(y);
+                if (x..=x + len).contains(&500) {
+                    clay_on_spring.push(y);
+                }
+            }
+        }
+        if min_x < 500 && 500 < max_x && !clay_on_spring.contains(&top) {
+            lines.shuffle(rng);
+            return lines.iter().map(|line| format!("{line}\n")).collect();
+        }
+    }
+}
+
+/// Days 19 and 21: a program of `len` instructions picked from `opcodes`, bound to `#ip 0..6`.
+///
+/// The instruction pointer is only ever moved forward, so every program terminates, and
+/// values are kept small so that nothing overflows (which is also why `mulr` isn't allowed).
+pub fn ip_program(rng: &mut impl Rng, len: usize, opcodes: &[&str]) -> String {
+    assert!(!opcodes.contains(&"mulr"), "`mulr` can overflow");
+    let ip = rng.gen_range(0..6);
+    let other_register = |rng: &mut dyn rand::RngCore| loop {
+        let register = rng.gen_range(0..6);
+        if register != ip {
+            break register;
+        }
+    };
+    let mut program = format!("#ip {ip}\n");
+    for _ in 0..len {
+        if rng.gen_bool(0.15) {
+            // conditional (or not) jump forward
+            if rng.gen_bool(0.5) {
+                writeln!(program, "addi {ip} {} {ip}", rng.gen_range(0..3)).unwrap();
+            } else {
+                let flag = other_register(rng);
+                writeln!(program, "gtri {} 0 {flag}", other_register(rng)).unwrap();
+                writeln!(program, "addr {ip} {flag} {ip}").unwrap();
+            }
+            continue;
+        }
+        let opcode = *opcodes.choose(rng).unwrap();
+        let mut arg = |kind| {
+            if kind == 'r' {
+                rng.gen_range(0..6)
+            } else if opcode == "muli" {
+                rng.gen_range(0..3)
+            } else {
+                rng.gen_range(0..20)
+            }
+        };
+        let kinds: Vec<char> = match &opcode[..2] {
+            "gt" | "eq" => opcode.chars().skip(2).collect(),
+            "se" => vec![opcode.chars().nth(3).unwrap(), 'i'],
+            _ => vec!['r', opcode.chars().nth(3).unwrap()],
+        };
+        let (a, b) = (arg(kinds[0]), arg(kinds[1]));
+        writeln!(program, "{opcode} {a} {b} {}", other_register(rng)).unwrap();
+    }
+    program
+}
+
+/// Plain interpreter of programs made by `ip_program`, returns the registers at exit.
+///
+/// It's the reference for the days' own interpreters, which are free to be clever.
+pub fn run_ip_program(program: &str) -> [usize; 6] {
+    let mut lines = program.lines();
+    let ip: usize = lines.next().unwrap()["#ip ".len()..].parse().unwrap();
+    let instructions: Vec<(&str, Vec<usize>)> = lines
+        .map(|line| {
+            let mut parts = line.split(' ');
+            let opcode = parts.next().unwrap();
+            (opcode, parts.map(|arg| arg.parse().unwrap()).collect())
+        })
+        .collect();
+    let mut regs = [0; 6];
+    while let Some((opcode, args)) = instructions.get(regs[ip]) {
+        let (a, b) = (args[0], args[1]);
+        let r = |x: usize| regs[x];
+        regs[args[2]] = match *opcode {
+            "addr" => r(a) + r(b),
+            "addi" => r(a) + b,
+            "muli" => r(a) * b,
+            "banr" => r(a) & r(b),
+            "bani" => r(a) & b,
+            "borr" => r(a) | r(b),
+            "bori" => r(a) | b,
+            "setr" => r(a),
+            "seti" => a,
+            "gtir" => usize::from(a > r(b)),
+            "gtri" => usize::from(r(a) > b),
+            "gtrr" => usize::from(r(a) > r(b)),
+            "eqir" => usize::from(a == r(b)),
+            "eqri" => usize::from(r(a) == b),
+            "eqrr" => usize::from(r(a) == r(b)),
+            other => panic!("unknown opcode {other:?}"),
+        };
+        regs[ip] += 1;
+    }
+    regs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::{day03, day04, day06, day07, day09, day17, day19, day21};
+    use crate::parse::{parse_lines, CheckInput};
+    use aoc_helpers::scaffold::{RowsOfChars, VecFromLines};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_inputs_parse() {
+        let mut rng = StdRng::seed_from_u64(2018);
+        for _ in 0..20 {
+            parse_lines::<day03::Claim>(&claims(&mut rng, 50, 100)).unwrap();
+            parse_lines::<day04::Entry>(&guard_log(&mut rng, 40, 5)).unwrap();
+            parse_lines::<day06::Coordinates>(&coordinates(&mut rng, 20, 100)).unwrap();
+            parse_lines::<day07::Dependency>(&dependencies(&mut rng, 10, 10)).unwrap();
+            parse_lines::<day09::Game>(&marble_game(&mut rng, 1000)).unwrap();
+            RowsOfChars::<char>::check(&cart_tracks(&mut rng, 4, 5)).unwrap();
+            parse_lines::<day17::Line>(&clay_veins(&mut rng, 20, 50)).unwrap();
+            VecFromLines::<day19::Line>::check(&ip_program(&mut rng, 20, &["addr", "seti"]))
+                .unwrap();
+            VecFromLines::<day21::Line>::check(&ip_program(&mut rng, 20, &["banr", "eqir"]))
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_polymer() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let polymer = polymer(&mut rng, 100, 3);
+        assert_eq!(polymer.trim_end().len(), 100);
+        assert!(polymer.trim_end().chars().all(|c| "abcABC".contains(c)));
+    }
+
+    #[test]
+    fn test_cart_tracks() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let tracks = cart_tracks(&mut rng, 3, 4);
+        assert_eq!(tracks.matches(['<', '>', '^', 'v']).count(), 4);
+        assert_eq!(tracks.matches('/').count(), tracks.matches('\\').count());
+    }
+}
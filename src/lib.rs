@@ -1 +1,11 @@
+pub mod automaton;
+pub mod bench;
+pub mod circular_list;
+pub mod days;
+pub mod gen;
+pub mod ocr;
+pub mod parse;
+pub mod search;
+pub mod summed_area;
 pub mod sweep;
+pub mod union_find;
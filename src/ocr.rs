@@ -0,0 +1,263 @@
+use std::collections::HashSet;
+use std::fmt;
+
+/// Block letter fonts puzzles draw their answers in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Font {
+    /// 6 pixels tall, mostly 4 wide with one empty column between letters.
+    Small,
+    /// 10 pixels tall, 6 wide with two empty columns between letters.
+    Large,
+}
+
+const SMALL_LETTERS: &str = "ABCEFGHIJKLOPRSUYZ";
+const SMALL: &str = "\
+.##...###....##...####..####...##...#..#..###....##..#..#..#......##...###...###....###..#..#..#...#..####
+#..#..#..#..#..#..#.....#.....#..#..#..#...#......#..#.#...#.....#..#..#..#..#..#..#.....#..#..#...#.....#
+#..#..###...#.....###...###...#.....####...#......#..##....#.....#..#..#..#..#..#..#.....#..#...#.#.....#.
+####..#..#..#.....#.....#.....#.##..#..#...#......#..#.#...#.....#..#..###...###....##...#..#....#.....#..
+#..#..#..#..#..#..#.....#.....#..#..#..#...#...#..#..#.#...#.....#..#..#.....#.#......#..#..#....#....#...
+#..#..###....##...####..#......###..#..#..###...##...#..#..####...##...#.....#..#..###....##.....#....####
+";
+
+const LARGE_LETTERS: &str = "ABCEFGHJKLNPRXZ";
+const LARGE: &str = "\
+..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######
+.#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#
+#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#
+#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#.
+#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#..
+######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#...
+#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#....
+#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.....
+#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.....
+#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######
+";
+
+impl Font {
+    /// Font of letters exactly `height` pixels tall.
+    pub fn for_height(height: usize) -> Option<Font> {
+        match height {
+            6 => Some(Font::Small),
+            10 => Some(Font::Large),
+            _ => None,
+        }
+    }
+
+    pub fn height(self) -> usize {
+        match self {
+            Font::Small => 6,
+            Font::Large => 10,
+        }
+    }
+
+    /// Empty columns between letters.
+    pub fn spacing(self) -> usize {
+        match self {
+            Font::Small => 1,
+            Font::Large => 2,
+        }
+    }
+
+    /// Every known letter with its glyph, trimmed to its lit columns.
+    fn glyphs(self) -> Vec<(char, Vec<Vec<bool>>)> {
+        let (letters, drawing) = match self {
+            Font::Small => (SMALL_LETTERS, SMALL),
+            Font::Large => (LARGE_LETTERS, LARGE),
+        };
+        let grid: Vec<Vec<bool>> = drawing
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect())
+            .collect();
+        letters.chars().zip(split(&grid)).collect()
+    }
+
+    /// `text` drawn in the font, `None` if it has a letter the font doesn't know.
+    pub fn draw(self, text: &str) -> Option<Vec<Vec<bool>>> {
+        let glyphs = self.glyphs();
+        let mut grid = vec![Vec::new(); self.height()];
+        for (idx, c) in text.chars().enumerate() {
+            let (_, glyph) = glyphs.iter().find(|(letter, _)| *letter == c)?;
+            for (row, glyph_row) in grid.iter_mut().zip(glyph) {
+                if idx > 0 {
+                    row.resize(row.len() + self.spacing(), false);
+                }
+                row.extend(glyph_row);
+            }
+        }
+        Some(grid)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OcrError {
+    /// No font has letters this tall.
+    Height(usize),
+    /// The glyph at `position` (counted from 0) isn't a letter of the font.
+    UnknownGlyph { position: usize, glyph: String },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OcrError::Height(height) => write!(f, "no font is {height} pixels tall"),
+            OcrError::UnknownGlyph { position, glyph } => {
+                write!(f, "unknown glyph at position {position}:\n{glyph}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Bounding box of the lit points, `x` grows to the right and `y` down.
+pub fn grid(points: impl IntoIterator<Item = (isize, isize)>) -> Vec<Vec<bool>> {
+    let points: HashSet<(isize, isize)> = points.into_iter().collect();
+    if points.is_empty() {
+        return Vec::new();
+    }
+    let min_x = points.iter().map(|p| p.0).min().unwrap();
+    let max_x = points.iter().map(|p| p.0).max().unwrap();
+    let min_y = points.iter().map(|p| p.1).min().unwrap();
+    let max_y = points.iter().map(|p| p.1).max().unwrap();
+    (min_y..=max_y)
+        .map(|y| (min_x..=max_x).map(|x| points.contains(&(x, y))).collect())
+        .collect()
+}
+
+/// The grid drawn with `#` and `.`, a line per row.
+pub fn render(grid: &[Vec<bool>]) -> String {
+    let mut result = String::new();
+    for row in grid {
+        result.extend(row.iter().map(|lit| if *lit { '#' } else { '.' }));
+        result.push('\n');
+    }
+    result
+}
+
+/// Splits the grid into glyphs at the empty columns.
+fn split(grid: &[Vec<bool>]) -> Vec<Vec<Vec<bool>>> {
+    let width = grid.first().map_or(0, |row| row.len());
+    let empty = |col: usize| grid.iter().all(|row| !row[col]);
+    let mut glyphs = Vec::new();
+    let mut col = 0;
+    while col < width {
+        let start = col;
+        while col < width && !empty(col) {
+            col += 1;
+        }
+        if start < col {
+            glyphs.push(grid.iter().map(|row| row[start..col].to_vec()).collect());
+        }
+        col += 1;
+    }
+    glyphs
+}
+
+/// Reads the letters in the grid, the font is picked by the height of the lit rows.
+pub fn read_grid(grid: &[Vec<bool>]) -> Result<String, OcrError> {
+    let lit = |row: &Vec<bool>| row.iter().any(|lit| *lit);
+    let top = grid.iter().position(lit).unwrap_or(0);
+    let bottom = grid.iter().rposition(lit).map_or(0, |row| row + 1);
+    let grid = &grid[top..bottom.max(top)];
+
+    let font = Font::for_height(grid.len()).ok_or(OcrError::Height(grid.len()))?;
+    let glyphs = font.glyphs();
+    split(grid)
+        .into_iter()
+        .enumerate()
+        .map(|(position, glyph)| {
+            glyphs
+                .iter()
+                .find(|(_, known)| *known == glyph)
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| OcrError::UnknownGlyph {
+                    position,
+                    glyph: render(&glyph),
+                })
+        })
+        .collect()
+}
+
+/// Reads the letters drawn by the lit points.
+pub fn read_points(points: impl IntoIterator<Item = (isize, isize)>) -> Result<String, OcrError> {
+    read_grid(&grid(points))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(drawing: &str) -> Vec<Vec<bool>> {
+        drawing
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_fonts() {
+        for (font, letters) in [(Font::Small, SMALL_LETTERS), (Font::Large, LARGE_LETTERS)] {
+            assert_eq!(font.glyphs().len(), letters.len());
+            let grid = font.draw(letters).unwrap();
+            assert_eq!(grid.len(), font.height());
+            assert_eq!(read_grid(&grid).unwrap(), letters);
+        }
+        assert_eq!(Font::Large.draw("AQ"), None);
+    }
+
+    #[test]
+    fn test_read() {
+        let small = "\
+..........
+.##..#....
+#..#.#....
+#....#....
+#....#....
+#..#.#....
+.##..####.
+";
+        assert_eq!(read_grid(&parse(small)).as_deref(), Ok("CL"));
+
+        let points = Font::Large
+            .draw("HEX")
+            .unwrap()
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                let lit: Vec<usize> = (0..row.len()).filter(|x| row[*x]).collect();
+                lit.into_iter()
+                    .map(move |x| (x as isize - 50, y as isize - 7))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(read_points(points).as_deref(), Ok("HEX"));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(read_grid(&parse("#\n#\n#\n")), Err(OcrError::Height(3)));
+        assert_eq!(read_grid(&[]), Err(OcrError::Height(0)));
+
+        let grid = parse(
+            "\
+####..#...#
+#.....#...#
+###...#...#
+#.....#...#
+#.....#...#
+####..#####
+",
+        );
+        let error = read_grid(&grid).unwrap_err();
+        assert_eq!(
+            error,
+            OcrError::UnknownGlyph {
+                position: 1,
+                glyph: "#...#\n#...#\n#...#\n#...#\n#...#\n#####\n".to_string(),
+            }
+        );
+        assert!(error
+            .to_string()
+            .starts_with("unknown glyph at position 1:\n#...#"));
+    }
+}
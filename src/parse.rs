@@ -0,0 +1,218 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::num::ParseIntError;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use aoc_helpers::scaffold::{RowsOfChars, VecFromLines};
+
+/// Why and where an input couldn't be parsed, lines and columns are counted from 1.
+///
+/// Line parsers only know the column, the line number, its text and the file are filled
+/// in by whoever splits the input into lines.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line: None,
+            column: 1,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Error pointing at `part` which has to be a slice of `text`.
+    pub fn at(text: &str, part: &str, message: impl Into<String>) -> Self {
+        Self {
+            column: column_of(text, part),
+            text: text.to_string(),
+            ..Self::new(message)
+        }
+    }
+
+    pub fn with_line(self, line: usize, text: &str) -> Self {
+        Self {
+            line: Some(line),
+            text: text.to_string(),
+            ..self
+        }
+    }
+
+    pub fn with_file(self, file: impl AsRef<Path>) -> Self {
+        Self {
+            file: Some(file.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => write!(f, "<input>:")?,
+        }
+        if let Some(line) = self.line {
+            write!(f, "{line}:")?;
+        }
+        write!(f, "{}: {}", self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<Infallible> for ParseError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<ParseIntError> for ParseError {
+    fn from(e: ParseIntError) -> Self {
+        Self::new(e.to_string())
+    }
+}
+
+/// 1-based column where `part` starts in `text`, falls back to 1 if it's not a slice of it.
+fn column_of(text: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    if offset <= text.len() {
+        text[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+/// Parses `part` of `text`, on failure points at the part.
+pub fn parse_field<T>(text: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    part.parse()
+        .map_err(|e| ParseError::at(text, part, format!("can't parse {part:?}: {e}")))
+}
+
+/// Parses every line of `input`, errors get the line number and text attached.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.parse()
+                .map_err(|e: T::Err| e.into().with_line(idx + 1, line))
+        })
+        .collect()
+}
+
+/// Input formats that can check the raw text and report where exactly it's broken.
+///
+/// `Parsed` matches what the scaffold hands to the solvers.
+pub trait CheckInput {
+    type Parsed;
+
+    fn parse_checked(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        Self::parse_checked(input).map(|_| ())
+    }
+}
+
+impl<T> CheckInput for VecFromLines<T>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    type Parsed = Vec<T>;
+
+    fn parse_checked(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+}
+
+impl<T> CheckInput for RowsOfChars<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<ParseError>,
+{
+    type Parsed = Vec<Vec<T>>;
+
+    fn parse_checked(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(column, c)| {
+                        T::try_from(c).map_err(|e| ParseError {
+                            column: column + 1,
+                            ..e.into().with_line(idx + 1, line)
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Pair(usize, usize);
+
+    impl FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (a, b) = s
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(s, s, "expected `<a>,<b>`"))?;
+            Ok(Pair(parse_field(s, a)?, parse_field(s, b)?))
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let pairs = parse_lines::<Pair>("1,2\n3,4").unwrap();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!((pairs[1].0, pairs[1].1), (3, 4));
+
+        let error = parse_lines::<Pair>("1,2\n3,4\n5,x6").unwrap_err();
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, "5,x6");
+        assert_eq!(
+            error.with_file("day.txt").to_string(),
+            "day.txt:3:3: can't parse \"x6\": invalid digit found in string: \"5,x6\""
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(VecFromLines::<Pair>::check("1,2\n3,4").is_ok());
+        let error = VecFromLines::<Pair>::check("1,2\n34").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), 1));
+        assert!(VecFromLines::<usize>::check("1\n-2").is_err());
+        assert!(RowsOfChars::<char>::check("ab\ncd").is_ok());
+    }
+}
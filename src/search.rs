@@ -0,0 +1,102 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// Finds the cheapest cost of getting from `start` to any state accepted by `is_goal`.
+///
+/// `neighbours` lists the states reachable from the given one together with the cost of the move,
+/// `heuristic` has to never overestimate the remaining cost (returning 0 turns this into Dijkstra).
+pub fn astar<S, N, I, H, G>(start: S, mut neighbours: N, heuristic: H, is_goal: G) -> Option<usize>
+where
+    S: Clone + Eq + Hash + Ord,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: Fn(&S) -> usize,
+    G: Fn(&S) -> bool,
+{
+    let mut costs: HashMap<S, usize> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    costs.insert(start.clone(), 0);
+    queue.push(Reverse((heuristic(&start), 0, start)));
+    while let Some(Reverse((_, cost, state))) = queue.pop() {
+        if is_goal(&state) {
+            return Some(cost);
+        }
+        if costs.get(&state).is_some_and(|best| *best < cost) {
+            // already reached it cheaper
+            continue;
+        }
+        for (next, move_cost) in neighbours(&state) {
+            let next_cost = cost + move_cost;
+            if costs.get(&next).is_none_or(|best| next_cost < *best) {
+                costs.insert(next.clone(), next_cost);
+                queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+/// Same as [`astar`] without any heuristic.
+pub fn dijkstra<S, N, I, G>(start: S, neighbours: N, is_goal: G) -> Option<usize>
+where
+    S: Clone + Eq + Hash + Ord,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    G: Fn(&S) -> bool,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Pos = (usize, usize);
+
+    fn grid_neighbours<'a>(grid: &'a [&'a str]) -> impl FnMut(&Pos) -> Vec<(Pos, usize)> + 'a {
+        move |&(row, col)| {
+            let mut result = Vec::new();
+            let candidates = [
+                (row.wrapping_sub(1), col),
+                (row + 1, col),
+                (row, col.wrapping_sub(1)),
+                (row, col + 1),
+            ];
+            for (r, c) in candidates {
+                match grid.get(r).and_then(|line| line.as_bytes().get(c)) {
+                    Some(b'.') => result.push(((r, c), 1)),
+                    Some(b'~') => result.push(((r, c), 3)),
+                    _ => {}
+                }
+            }
+            result
+        }
+    }
+
+    #[test]
+    fn test_grid_paths() {
+        // going through the water is shorter than walking around it
+        let grid = ["..~..", ".#.#.", "....."];
+        let goal = |p: &Pos| *p == (0, 4);
+        let manhattan = |&(r, c): &Pos| r + (4 - c);
+        assert_eq!(dijkstra((0, 0), grid_neighbours(&grid), goal), Some(6));
+        assert_eq!(
+            astar((0, 0), grid_neighbours(&grid), manhattan, goal),
+            Some(6)
+        );
+        assert_eq!(
+            dijkstra((2, 0), grid_neighbours(&grid), |p| *p == (2, 4)),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let grid = ["..#", "###", "..."];
+        assert_eq!(
+            dijkstra((0, 0), grid_neighbours(&grid), |p| *p == (2, 2)),
+            None
+        );
+    }
+}
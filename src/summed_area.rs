@@ -0,0 +1,216 @@
+use std::ops::{Add, Sub};
+use std::thread;
+
+/// Sums of every rectangle of a grid in constant time.
+///
+/// Cells are numbered from 1 like in the puzzles, `x` from left to right and `y` from top
+/// to bottom. Row and column 0 of the table are the zero border.
+#[derive(Clone, Debug)]
+pub struct SummedAreaTable<T> {
+    width: usize,
+    height: usize,
+    sums: Vec<T>,
+}
+
+/// Square with the top left cell at `x`, `y`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Square<T> {
+    pub x: usize,
+    pub y: usize,
+    pub size: usize,
+    pub sum: T,
+}
+
+/// Rectangle with the top left cell at `x`, `y`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rectangle<T> {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+    pub sum: T,
+}
+
+impl<T> SummedAreaTable<T>
+where
+    T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    /// Table of the grid with `value(x, y)` in every cell.
+    pub fn new(width: usize, height: usize, value: impl Fn(usize, usize) -> T) -> Self {
+        let mut sums = vec![T::default(); (width + 1) * (height + 1)];
+        for y in 1..=height {
+            let mut row_sum = T::default();
+            for x in 1..=width {
+                row_sum = row_sum + value(x, y);
+                sums[y * (width + 1) + x] = row_sum + sums[(y - 1) * (width + 1) + x];
+            }
+        }
+        Self {
+            width,
+            height,
+            sums,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Sum of the rectangle from the top left corner to `x`, `y` included.
+    fn corner(&self, x: usize, y: usize) -> T {
+        self.sums[y * (self.width + 1) + x]
+    }
+
+    /// Sum of the `width` by `height` rectangle with the top left cell at `x`, `y`.
+    pub fn sum(&self, x: usize, y: usize, width: usize, height: usize) -> T {
+        let (right, bottom) = (x + width - 1, y + height - 1);
+        assert!(
+            x > 0 && y > 0 && right <= self.width && bottom <= self.height,
+            "rectangle out of the grid"
+        );
+        self.corner(right, bottom) + self.corner(x - 1, y - 1)
+            - self.corner(x - 1, bottom)
+            - self.corner(right, y - 1)
+    }
+
+    /// Rectangle of the given size with the biggest sum, the leftmost and then topmost one
+    /// if there's a tie. `None` if it doesn't fit in the grid.
+    pub fn best_rectangle(&self, width: usize, height: usize) -> Option<Rectangle<T>> {
+        if width == 0 || height == 0 || width > self.width || height > self.height {
+            return None;
+        }
+        let mut best: Option<Rectangle<T>> = None;
+        for x in 1..=(self.width - width + 1) {
+            for y in 1..=(self.height - height + 1) {
+                let sum = self.sum(x, y, width, height);
+                if best.is_none_or(|best| best.sum < sum) {
+                    best = Some(Rectangle {
+                        x,
+                        y,
+                        width,
+                        height,
+                        sum,
+                    });
+                }
+            }
+        }
+        best
+    }
+
+    pub fn best_square(&self, size: usize) -> Option<Square<T>> {
+        self.best_rectangle(size, size).map(|rect| Square {
+            x: rect.x,
+            y: rect.y,
+            size,
+            sum: rect.sum,
+        })
+    }
+}
+
+impl<T> SummedAreaTable<T>
+where
+    T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T> + Send + Sync,
+{
+    /// Square of any size with the biggest sum, the smallest one if there's a tie.
+    ///
+    /// Sizes are split between threads, each size is a full scan of the table.
+    pub fn best_any_square(&self) -> Option<Square<T>> {
+        let sizes = self.width.min(self.height);
+        let threads = thread::available_parallelism()
+            .map_or(1, |threads| threads.get())
+            .min(sizes.max(1));
+        let better = |a: Square<T>, b: Square<T>| {
+            if (b.sum, a.size) > (a.sum, b.size) {
+                b
+            } else {
+                a
+            }
+        };
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..threads)
+                .map(|first| {
+                    scope.spawn(move || {
+                        (first + 1..=sizes)
+                            .step_by(threads)
+                            .filter_map(|size| self.best_square(size))
+                            .reduce(better)
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .filter_map(|worker| worker.join().unwrap())
+                .reduce(better)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn brute_force_sum(grid: &[Vec<i32>], x: usize, y: usize, width: usize, height: usize) -> i32 {
+        grid[y - 1..y - 1 + height]
+            .iter()
+            .map(|row| row[x - 1..x - 1 + width].iter().sum::<i32>())
+            .sum()
+    }
+
+    #[test]
+    fn test_sum() {
+        let grid = [[1, 2, 3], [4, 5, 6]];
+        let table = SummedAreaTable::new(3, 2, |x, y| grid[y - 1][x - 1]);
+        assert_eq!((table.width(), table.height()), (3, 2));
+        assert_eq!(table.sum(1, 1, 3, 2), 21);
+        assert_eq!(table.sum(2, 2, 2, 1), 11);
+        assert_eq!(table.sum(3, 1, 1, 1), 3);
+        assert_eq!(
+            table.best_square(2).map(|s| (s.x, s.y, s.sum)),
+            Some((2, 1, 16))
+        );
+        assert_eq!(table.best_square(3), None);
+        assert_eq!(table.best_rectangle(1, 2).map(|r| r.sum), Some(9));
+        assert_eq!(table.best_any_square().map(|s| s.size), Some(2));
+    }
+
+    #[test]
+    fn test_random_grids() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..20 {
+            let (width, height) = (rng.gen_range(1..15), rng.gen_range(1..15));
+            let grid: Vec<Vec<i32>> = (0..height)
+                .map(|_| (0..width).map(|_| rng.gen_range(-9..=9)).collect())
+                .collect();
+            let table = SummedAreaTable::new(width, height, |x, y| grid[y - 1][x - 1]);
+
+            let mut best: Option<Square<i32>> = None;
+            for size in 1..=width.min(height) {
+                for x in 1..=width - size + 1 {
+                    for y in 1..=height - size + 1 {
+                        let sum = brute_force_sum(&grid, x, y, size, size);
+                        assert_eq!(table.sum(x, y, size, size), sum);
+                        if best.is_none_or(|best| best.sum < sum) {
+                            best = Some(Square { x, y, size, sum });
+                        }
+                    }
+                }
+            }
+            assert_eq!(table.best_any_square(), best);
+
+            let (w, h) = (rng.gen_range(1..=width), rng.gen_range(1..=height));
+            let rect = table.best_rectangle(w, h).unwrap();
+            assert_eq!(rect.sum, brute_force_sum(&grid, rect.x, rect.y, w, h));
+            for x in 1..=width - w + 1 {
+                for y in 1..=height - h + 1 {
+                    assert!(brute_force_sum(&grid, x, y, w, h) <= rect.sum);
+                }
+            }
+        }
+    }
+}
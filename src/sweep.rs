@@ -108,19 +108,16 @@ mod test {
 
     #[test]
     fn test_simple_range() {
-        let ranges = vec![(0..2, "A"), (4..5, "B"), (1..4, "C")];
+        let ranges = [(0..2, "A"), (4..5, "B"), (1..4, "C")];
         let mut builder = SweeperBuilder::default();
         for r in ranges.iter() {
             builder.insert(r.0.clone(), &r.1);
         }
         let mut sweeper = builder.build();
-        assert_eq!(sweeper.next(), Some((0..1, vec!["A"].iter().collect())));
-        assert_eq!(
-            sweeper.next(),
-            Some((1..2, vec!["A", "C"].iter().collect()))
-        );
-        assert_eq!(sweeper.next(), Some((2..4, vec!["C"].iter().collect())));
-        assert_eq!(sweeper.next(), Some((4..5, vec!["B"].iter().collect())));
+        assert_eq!(sweeper.next(), Some((0..1, ["A"].iter().collect())));
+        assert_eq!(sweeper.next(), Some((1..2, ["A", "C"].iter().collect())));
+        assert_eq!(sweeper.next(), Some((2..4, ["C"].iter().collect())));
+        assert_eq!(sweeper.next(), Some((4..5, ["B"].iter().collect())));
         assert_eq!(sweeper.next(), None);
     }
 }
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+/// Disjoint sets over the indexes `0..len`.
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    sets_count: usize,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            sets_count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Adds a new single element set and returns its index.
+    pub fn push(&mut self) -> usize {
+        let idx = self.parents.len();
+        self.parents.push(idx);
+        self.sizes.push(1);
+        self.sets_count += 1;
+        idx
+    }
+
+    /// Returns the representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // compress the path so next lookups are quicker
+        let mut x = x;
+        while self.parents[x] != root {
+            let next = self.parents[x];
+            self.parents[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    /// Merges sets containing `a` and `b`, returns `false` if they were already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.sets_count -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+
+    pub fn sets_count(&self) -> usize {
+        self.sets_count
+    }
+
+    /// Lists members of every set, sets are ordered by their smallest element.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut positions = HashMap::with_capacity(self.sets_count);
+        let mut sets: Vec<Vec<usize>> = Vec::with_capacity(self.sets_count);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            let position = *positions.entry(root).or_insert_with(|| {
+                sets.push(Vec::new());
+                sets.len() - 1
+            });
+            sets[position].push(x);
+        }
+        sets
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.sets_count(), 6);
+        assert!(sets.union(0, 3));
+        assert!(sets.union(4, 3));
+        assert!(!sets.union(0, 4));
+        assert!(sets.union(1, 5));
+        assert_eq!(sets.sets_count(), 3);
+        assert!(sets.same_set(0, 4));
+        assert!(!sets.same_set(0, 1));
+        assert_eq!(sets.set_size(3), 3);
+        assert_eq!(sets.sets(), vec![vec![0, 3, 4], vec![1, 5], vec![2]]);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut sets = UnionFind::new(0);
+        assert!(sets.is_empty());
+        let a = sets.push();
+        let b = sets.push();
+        assert_eq!(sets.sets_count(), 2);
+        sets.union(a, b);
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets.sets(), vec![vec![0, 1]]);
+    }
+}
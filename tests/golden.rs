@@ -0,0 +1,208 @@
+//! Runs every input that has an expected answer in `outputs/` and compares the results line by line.
+//!
+//! Outputs are named after their input (`day07-example.txt`), a part letter right after the day
+//! number (`day12b-example.txt`) limits the comparison to that part only. An input without an
+//! output fails the test unless it's listed in `WITHOUT_OUTPUT`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use advent_of_code_2018::days;
+
+/// Inputs with a separate puzzle on every line, the answers are listed line by line.
+const ONE_PUZZLE_PER_LINE: &[&str] = &["day09-example.txt"];
+
+/// Inputs using other puzzle parameters than the real ones, part 2 gets them through
+/// `Day::part2_with`.
+const PARAMETERS: &[(&str, &[usize])] =
+    &[("day06-example.txt", &[32]), ("day07-example.txt", &[2, 0])];
+
+/// Inputs known to have no expected output yet.
+const WITHOUT_OUTPUT: &[&str] = &[
+    "day01.txt",
+    "day02.txt",
+    "day02a-example.txt",
+    "day02b-example.txt",
+    "day03-example.txt",
+    "day03.txt",
+    "day04-example.txt",
+    "day04.txt",
+    "day05-example.txt",
+    "day05.txt",
+    "day10-example.txt",
+    "day11.txt",
+    "day13-example.txt",
+    "day13-example2.txt",
+    "day13.txt",
+    "day14.txt",
+    "day15.txt",
+    "day17.txt",
+    "day18.txt",
+    "day19-example.txt",
+    "day19.txt",
+    "day20.txt",
+    "day21-notes.txt",
+    "day21.txt",
+    "day22.txt",
+    "day23.txt",
+    "day24.txt",
+    "day25.txt",
+];
+
+#[derive(Debug)]
+struct Name {
+    day: u8,
+    part: Option<u8>,
+    variant: String,
+}
+
+impl Name {
+    fn parse(path: &Path) -> Option<Self> {
+        let stem = path.file_stem()?.to_str()?.strip_prefix("day")?;
+        let day = stem.get(..2)?.parse().ok()?;
+        let rest = &stem[2..];
+        let (part, variant) = match rest.chars().next() {
+            Some('a') => (Some(1), &rest[1..]),
+            Some('b') => (Some(2), &rest[1..]),
+            _ => (None, rest),
+        };
+        Some(Self {
+            day,
+            part,
+            variant: variant.to_string(),
+        })
+    }
+}
+
+fn txt_files(dir: &str) -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(dir);
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("{}: {}", dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Finds the input for an output, part specific inputs win over the shared one.
+fn find_input<'a>(inputs: &'a [PathBuf], output: &Name) -> Option<&'a PathBuf> {
+    let part_letter = match output.part {
+        Some(1) => "a",
+        Some(2) => "b",
+        _ => "",
+    };
+    let candidates = [
+        format!("day{:02}{}{}.txt", output.day, part_letter, output.variant),
+        format!("day{:02}{}.txt", output.day, output.variant),
+    ];
+    candidates.iter().find_map(|candidate| {
+        inputs.iter().find(|input| {
+            input
+                .file_name()
+                .is_some_and(|name| name == candidate.as_str())
+        })
+    })
+}
+
+fn answers(name: &Name, input: &str, params: Option<&[usize]>) -> Result<Vec<String>, String> {
+    let day = days::find(name.day).ok_or_else(|| format!("day {} is not solved", name.day))?;
+    let parts = match name.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    parts
+        .into_iter()
+        .filter_map(|part| match (part, params) {
+            (2, Some(params)) => Some(
+                day.part2_with
+                    .map(|solver| solver(input, params))
+                    .ok_or_else(|| format!("day {} doesn't take parameters", name.day)),
+            ),
+            _ => day.solver(part).map(|solver| Ok(solver(input))),
+        })
+        .collect()
+}
+
+fn lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = text
+        .lines()
+        .map(|line| line.trim_end().to_string())
+        .collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+#[test]
+fn test_golden_answers() {
+    let inputs = txt_files("inputs");
+    let outputs = txt_files("outputs");
+
+    let mut failures = Vec::new();
+    let mut covered = Vec::new();
+    for output_path in &outputs {
+        let name = Name::parse(output_path)
+            .unwrap_or_else(|| panic!("unexpected output name: {}", output_path.display()));
+        let input_path = match find_input(&inputs, &name) {
+            Some(path) => path,
+            None => {
+                failures.push(format!("{}: no matching input", output_path.display()));
+                continue;
+            }
+        };
+        covered.push(input_path.clone());
+
+        let input = fs::read_to_string(input_path).unwrap();
+        let expected = lines(&fs::read_to_string(output_path).unwrap());
+        let one_per_line = ONE_PUZZLE_PER_LINE
+            .iter()
+            .any(|file| input_path.ends_with(file));
+        let params = PARAMETERS
+            .iter()
+            .find(|(file, _)| input_path.ends_with(file))
+            .map(|(_, params)| *params);
+        let answers = if one_per_line {
+            input
+                .lines()
+                .map(|line| answers(&name, line, params))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|answers| answers.concat())
+        } else {
+            answers(&name, &input, params)
+        };
+        match answers {
+            Ok(answers) => {
+                let actual: Vec<String> = answers.iter().flat_map(|answer| lines(answer)).collect();
+                if actual != expected {
+                    failures.push(format!(
+                        "{}: expected {:?}, got {:?}",
+                        output_path.display(),
+                        expected,
+                        actual
+                    ));
+                }
+            }
+            Err(e) => failures.push(format!("{}: {}", output_path.display(), e)),
+        }
+    }
+
+    let missing: Vec<String> = inputs
+        .iter()
+        .filter(|input| !covered.contains(input))
+        .filter_map(|input| input.file_name()?.to_str().map(ToString::to_string))
+        .collect();
+    for file in &missing {
+        if !WITHOUT_OUTPUT.contains(&file.as_str()) {
+            failures.push(format!("{file}: no expected output"));
+        }
+    }
+    for file in WITHOUT_OUTPUT {
+        if !missing.iter().any(|missing| missing == file) {
+            failures.push(format!("{file}: listed as having no output but it has one"));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}